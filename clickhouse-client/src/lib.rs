@@ -69,6 +69,30 @@ pub struct ClickhouseQuery {
     pub headers: BTreeMap<String, String>,
     pub compression: Compression,
 }
+impl ClickhouseQuery {
+    /// Bind a value to the `{name:Type}` parameter of the query.
+    ///
+    /// Backslashes, tabs and newlines are escaped, so that user-supplied values are passed as is.
+    pub fn with_param(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.params
+            .insert(format!("param_{}", name), escape_param(&value.into()));
+        self
+    }
+}
+// Clickhouse parses parameter values in the escaped (TabSeparated) format.
+fn escape_param(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
 impl From<&str> for ClickhouseQuery {
     fn from(source: &str) -> Self {
         Self {
@@ -137,3 +161,20 @@ impl HttpClient {
         self.prepare_request(query)?.send().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_param() {
+        let client = HttpClient::new("http://localhost:8123".parse().unwrap(), false);
+        let query = ClickhouseQuery::from("SELECT {s:String}").with_param("s", "a\\b\tc\nd'e");
+        let request = client.prepare_request(&query).unwrap().0.build().unwrap();
+        let params: BTreeMap<_, _> = request.url().query_pairs().into_owned().collect();
+        assert_eq!(
+            params,
+            BTreeMap::from([("param_s".to_string(), "a\\\\b\\tc\\nd'e".to_string())])
+        );
+    }
+}
@@ -98,7 +98,24 @@ fn decode_query(
     };
     if let Some(limit) = limit {
         match &mut query {
-            Query::Select(query) => query.limit = Some(limit),
+            // Only replace the number of rows, keeping the offset and WITH TIES.
+            Query::Select(query) => {
+                query.limit = Some(match query.limit.take() {
+                    Some(l) => {
+                        sqlsonnet::queries::limit::Limit::new(limit, l.offset(), l.with_ties())
+                    }
+                    None => limit.into(),
+                })
+            }
+            Query::Insert(_)
+            | Query::SetOp(_)
+            | Query::CreateTable(_)
+            | Query::CreateView(_)
+            | Query::CreateMaterializedView(_)
+            | Query::Alter(_)
+            | Query::Delete(_)
+            | Query::Explain(_)
+            | Query::Raw(_) => {}
         }
     }
     // Submit to Clickhouse and forward reply
@@ -123,7 +123,7 @@ mod websocket {
         }
         // Replace ${var} by var.
         // In Grafana, u.string(${var:singlequote}) will be replaced by u.string('value'),
-        // which is '"' + 'value' + '"'
+        // which is the escaped SQL string literal 'value'
         fn replace_variables(mut self) -> Self {
             self.jsonnet = VARIABLE_RE
                 .replace_all(&self.jsonnet, |caps: &regex::Captures| {
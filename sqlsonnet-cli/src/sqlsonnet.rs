@@ -56,7 +56,7 @@ struct Flags {
     /// Send query to Clickhouse proxy (--proxy-url) for execution
     #[clap(long, short, conflicts_with = "from_sql", requires = "clickhouse_url")]
     execute: bool,
-    /// Output format for execution
+    /// Output format for execution, unless set by the query's `format`
     #[clap(long, default_value = "PrettyMonoBlock")]
     execute_format: String,
     /// Watch for file changes
@@ -3,6 +3,25 @@
 {
   op(operator, l, empty=null): if std.length(l) == 0 then empty else if std.length(l) == 1 then l[0] else [l[0], operator, self.op(operator, l[1:], empty)],
   select(x): { select: x },
+  // DDL
+  create_table(x): { createTable: x },
+  create_view(x): { createView: x },
+  create_materialized_view(x): { createMaterializedView: x },
+  column(name, type, default=null, codec=[]): { name: name, type: type, codec: codec } + (if default != null then { default: default } else {}),
+  // ALTER TABLE table command, ... with commands such as { delete: { where: expr } } or { dropColumn: name }
+  alter(table, commands): { alter: { table: table, commands: commands } },
+  // Lightweight DELETE FROM table WHERE expr
+  delete(table, where): { delete: { from: table, where: where } },
+  // EXPLAIN kind query, with kind one of plan, pipeline, syntax, estimate or ast
+  explain(query, kind='plan', settings={}): { explain: { kind: kind, settings: settings, query: query } },
+  // Statement passed through verbatim, e.g. SYSTEM FLUSH LOGS
+  raw(sql): { raw: sql },
+  // Set operations on a list of select queries
+  set_op(kind, queries): { setOp: { kind: kind, queries: queries } },
+  union_all(queries): self.set_op('union-all', queries),
+  union_distinct(queries): self.set_op('union-distinct', queries),
+  intersect(queries): self.set_op('intersect', queries),
+  except(queries): self.set_op('except', queries),
   where_and(expr_list): { where: $.and(expr_list + (if 'where' in super then [super.where] else [])) },
   having_and(expr): { having: $.and([expr] + (if 'having' in super then [super.having] else [])) },
   // Operators
@@ -12,7 +31,8 @@
   div(a, b): self.op('/', [a, b]),
   sub(a, b): self.op('-', [a, b]),
   or(l): self.op('OR', l),
-  string(s): "'" + s + "'",
+  // SQL string literal, with backslashes and quotes escaped
+  string(s): "'" + std.strReplace(std.strReplace(s, '\\', '\\\\'), "'", "\\'") + "'",
   eq(a, b): [a, '=', b],
   neq(a, b): [a, '!=', b],
   ge(a, b): [a, '>=', b],
@@ -22,11 +42,33 @@
   leq(a, b): [a, '<=', b],
   like(a, b): [a, 'LIKE', b],
   in_(a, b): [a, 'IN', b],
+  not_in(a, b): [a, 'NOT IN', b],
+  ilike(a, b): [a, 'ILIKE', b],
+  is_null(expr): { isNull: expr },
+  is_not_null(expr): { isNull: expr, not: true },
+  // expr BETWEEN low AND high
+  between(expr, low, high): { between: expr, low: low, high: high },
   not(expr): ['NOT', expr],
+  // CASE [operand] WHEN cond THEN result ... [ELSE default] END, with when a list of [cond, result] pairs.
+  case(when, default=null, operand=null): { when: when } + (if default != null then { 'else': default } else {}) + (if operand != null then { case: operand } else {}),
   // expr AS as, overriding existing aliases.
   as(expr, as): if std.isObject(expr) && std.objectHas(expr, 'alias') then { expr: expr.expr, alias: as } else { expr: expr, alias: as },
+  // Literals
+  literal(s): { string: s },
+  array(l): { array: l },
+  tuple(l): { tuple: l },
+  // map('key', value, ...) from an object
+  map(obj): { map: obj },
+  // CAST(expr AS type)
+  cast(expr, type): { cast: expr, type: type },
+  // Query parameter {name:type}, passed to Clickhouse as param_name
+  param(name, type): { param: name, type: type },
   // Functions
   fn(name, params): { fn: name, params: params },
   count(expr='*', as='c'): self.as(self.fn('count', [expr]), as),
   rand(): self.fn('rand', []),
+  // params -> body, with params a name or a list of names.
+  lambda(params, body): { lambda: if std.isArray(params) then params else [params], body: body },
+  // fn(params) OVER (window), where window has optional partitionBy, orderBy and frame fields.
+  over(fn, window={}): fn { over: window },
 }
@@ -19,6 +19,24 @@ fn show_pair(pair: Pair<Rule>, indent: usize) {
     }
 }
 
+// Contents of a single-quoted SQL string literal
+fn unquote(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s[1..s.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            c => out.extend(c),
+        }
+    }
+    out
+}
+
 pub(super) trait FromParsed: Sized {
     fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError>;
 }
@@ -26,11 +44,24 @@ pub(super) trait FromParsed: Sized {
 impl FromParsed for queries::Query {
     fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
         let query = match parsed.as_rule() {
-            Rule::select => parsed,
             Rule::query => parsed.into_inner().next().unwrap(),
-            _ => unreachable!(),
+            _ => parsed,
         };
-        FromParsed::parse(query).map(Self::Select)
+        match query.as_rule() {
+            Rule::set_op => {
+                let mut selects = query
+                    .clone()
+                    .into_inner()
+                    .filter(|p| p.as_rule() == Rule::select);
+                if let (Some(select), None) = (selects.next(), selects.next()) {
+                    return FromParsed::parse(select).map(Self::Select);
+                }
+                FromParsed::parse(query).map(Self::SetOp)
+            }
+            Rule::insert => FromParsed::parse(query).map(Self::Insert),
+            Rule::explain => FromParsed::parse(query).map(Self::Explain),
+            _ => unreachable!(),
+        }
     }
 }
 
@@ -39,7 +70,7 @@ impl FromParsed for queries::Queries {
         assert_eq!(parsed.as_rule(), Rule::queries);
         parsed
             .into_inner()
-            .filter(|p| p.as_rule() == Rule::select)
+            .filter(|p| p.as_rule() != Rule::EOI)
             .map(|parsed| queries::Query::parse(parsed))
             .collect::<Result<Vec<_>, _>>()
             .map(|r| r.into())
@@ -64,29 +95,19 @@ impl FromParsed for queries::Expr {
             .clone()
             .into_inner()
             .flatten()
-            .any(|p| p.as_rule() == Rule::select)
+            .any(|p| [Rule::select, Rule::window_call].contains(&p.as_rule()))
         {
             return Ok(parsed.as_str().into());
         }
-        const SEQ: bool = false;
         match parsed.as_rule() {
-            Rule::expr => {
+            Rule::expr | Rule::cast_expr | Rule::between_bound => {
                 let mut parsed = parsed.into_inner();
-                let mut term1 = Self::parse(parsed.next().unwrap())?;
-                let mut v: Vec<(queries::Operator, Box<Self>)> = vec![];
+                let term = Self::parse(parsed.next().unwrap())?;
+                let mut ops = vec![];
                 let mut alias = None;
                 for parsed in parsed {
                     match parsed.as_rule() {
-                        Rule::op_term => {
-                            let mut parsed = parsed.into_inner();
-                            let op = queries::Operator(parsed.next().unwrap().as_str().into());
-                            let term = Self::parse(parsed.next().unwrap())?;
-                            if SEQ {
-                                v.push((op, Box::new(term)));
-                            } else {
-                                term1 = term1.operator(op, term);
-                            }
-                        }
+                        Rule::op_term => ops.push(Op::parse(parsed)?),
                         Rule::r#as => {
                             let id = parsed
                                 .into_inner()
@@ -98,42 +119,226 @@ impl FromParsed for queries::Expr {
                         _ => unreachable!(),
                     }
                 }
-                if SEQ && !v.is_empty() {
-                    term1 = Self::OperatorSeq(Box::new(term1), v)
-                }
+                let mut expr = Op::climb(term, &mut ops.into_iter().peekable(), 0);
                 if let Some(alias) = alias {
-                    term1 = Self::Aliased {
-                        expr: Box::new(term1),
+                    expr = Self::Aliased {
+                        expr: Box::new(expr),
                         alias,
                     }
                 }
-                Ok(term1)
+                Ok(expr)
+            }
+            Rule::lambda => {
+                let mut parsed = parsed.into_inner();
+                let params = parsed.next().unwrap();
+                let lambda = match params.as_rule() {
+                    Rule::identifiers => params.into_inner().map(|p| p.as_str().into()).collect(),
+                    _ => vec![params.as_str().into()],
+                };
+                let body = Box::new(Self::parse(parsed.next().unwrap())?);
+                Ok(Self::Lambda { lambda, body })
             }
             Rule::term => {
-                let term = parsed.as_str();
-                let parsed = parsed.into_inner();
-                if let Some(parsed) = parsed.find_first_tagged("select") {
+                let mut parsed = parsed.into_inner();
+                let expr = Self::parse(parsed.next().unwrap())?;
+                Ok(match parsed.next() {
+                    Some(data_type) => Self::Cast {
+                        cast: Box::new(expr),
+                        r#type: data_type.as_str().into(),
+                    },
+                    None => expr,
+                })
+            }
+            Rule::atom => {
+                let atom = parsed.as_str();
+                let parsed = parsed.into_inner().next().unwrap();
+                if parsed.as_node_tag() == Some("select") {
                     let select = queries::Query::parse(parsed)?;
                     return Ok(Self::Subquery(Box::new(select)));
                 }
-                Ok(term.into())
+                match parsed.as_rule() {
+                    // (expr)
+                    Rule::expr => Self::parse(parsed),
+                    Rule::tuple => Ok(Self::Tuple {
+                        tuple: queries::ExprList(
+                            parsed
+                                .into_inner()
+                                .map(FromParsed::parse)
+                                .collect::<Result<_, _>>()?,
+                        ),
+                    }),
+                    Rule::array => Ok(Self::Array {
+                        array: match parsed.into_inner().next() {
+                            Some(exprs) => FromParsed::parse(exprs)?,
+                            None => Default::default(),
+                        },
+                    }),
+                    Rule::cast => {
+                        // CAST expr AS type
+                        let mut parsed = parsed.into_inner();
+                        let cast = Box::new(Self::parse(parsed.nth(1).unwrap())?);
+                        let r#type = parsed.nth(1).unwrap().as_str().into();
+                        Ok(Self::Cast { cast, r#type })
+                    }
+                    Rule::window_call => {
+                        let mut parsed = parsed.into_inner();
+                        let mut function = parsed.next().unwrap().into_inner();
+                        let r#fn = function.next().unwrap().as_str().into();
+                        let params = match function.next() {
+                            Some(exprs) => FromParsed::parse(exprs)?,
+                            None => Default::default(),
+                        };
+                        let over = FromParsed::parse(parsed.last().unwrap())?;
+                        Ok(Self::Window {
+                            r#fn,
+                            params,
+                            over: Box::new(over),
+                        })
+                    }
+                    _ => Ok(atom.into()),
+                }
             }
             _ => unreachable!(),
         }
     }
 }
+// Operator applied to the expression on its left
+enum Op {
+    Infix(queries::Operator, queries::Expr),
+    IsNull {
+        not: bool,
+    },
+    Between {
+        not: bool,
+        low: queries::Expr,
+        high: queries::Expr,
+    },
+}
+impl FromParsed for Op {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::op_term);
+        let mut parsed = parsed.into_inner();
+        let first = parsed.next().unwrap();
+        Ok(match first.as_rule() {
+            Rule::infix_op => {
+                // e.g. NOT  IN -> NOT IN
+                let op = first
+                    .as_str()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let term = queries::Expr::parse(parsed.next().unwrap())?;
+                Self::Infix(queries::Operator(op), term)
+            }
+            Rule::IS_NULL => Self::IsNull { not: false },
+            Rule::IS_NOT_NULL => Self::IsNull { not: true },
+            Rule::between => {
+                let mut parsed = first.into_inner();
+                let not = parsed.next().unwrap().as_rule() == Rule::NOT_BETWEEN;
+                let low = queries::Expr::parse(parsed.next().unwrap())?;
+                let high = queries::Expr::parse(parsed.nth(1).unwrap())?;
+                Self::Between { not, low, high }
+            }
+            _ => unreachable!(),
+        })
+    }
+}
+impl Op {
+    // Higher binds tighter, following Clickhouse
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Infix(op, _) => match op.0.to_lowercase().as_str() {
+                "*" | "/" | "%" => 8,
+                "+" | "-" => 7,
+                "||" => 6,
+                "and" => 2,
+                "or" => 1,
+                // Comparisons, [NOT] [I]LIKE, [GLOBAL] [NOT] IN
+                _ => 5,
+            },
+            Self::IsNull { .. } => 4,
+            Self::Between { .. } => 3,
+        }
+    }
+    // Precedence climbing, with left associativity
+    fn climb(
+        mut left: queries::Expr,
+        ops: &mut std::iter::Peekable<std::vec::IntoIter<Op>>,
+        min_precedence: u8,
+    ) -> queries::Expr {
+        while let Some(op) = ops.next_if(|op| op.precedence() >= min_precedence) {
+            let precedence = op.precedence();
+            left = match op {
+                Self::Infix(operator, right) => {
+                    let right = Self::climb(right, ops, precedence + 1);
+                    left.operator(operator, right)
+                }
+                Self::IsNull { not } => queries::Expr::IsNull {
+                    expr: Box::new(left),
+                    not,
+                },
+                Self::Between { not, low, high } => queries::Expr::Between {
+                    expr: Box::new(left),
+                    low: Box::new(low),
+                    high: Box::new(high),
+                    not,
+                },
+            }
+        }
+        left
+    }
+}
+
 // expr ASC or expr DESC
 impl FromParsed for queries::order_by::Expr {
     fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
         assert_eq!(parsed.as_rule(), Rule::order_expr);
         let mut parsed = parsed.into_inner();
         let expr = queries::Expr::parse(parsed.next().unwrap())?;
-        let ordering = if let Some(parsed) = parsed.next() {
-            queries::order_by::Ordering::parse(parsed)?
-        } else {
-            Default::default()
-        };
-        Ok(Self::new(expr, ordering))
+        let mut order = Default::default();
+        let mut nulls = None;
+        let mut collate = None;
+        let mut fill = None;
+        for p in parsed {
+            match p.as_rule() {
+                Rule::ordering => order = queries::order_by::Ordering::parse(p)?,
+                Rule::nulls => {
+                    nulls = Some(match p.into_inner().next().unwrap().as_rule() {
+                        Rule::NULLS_FIRST => queries::order_by::Nulls::First,
+                        Rule::NULLS_LAST => queries::order_by::Nulls::Last,
+                        _ => unreachable!(),
+                    })
+                }
+                Rule::collate => {
+                    collate = Some(unquote(p.into_inner().nth(1).unwrap().as_str()));
+                }
+                Rule::fill => {
+                    let mut f = queries::order_by::Fill::default();
+                    let mut p = p.into_inner().skip(1);
+                    while let (Some(keyword), Some(expr)) = (p.next(), p.next()) {
+                        let expr = Some(FromParsed::parse(expr)?);
+                        match keyword.as_rule() {
+                            Rule::FROM => f.from = expr,
+                            Rule::TO => f.to = expr,
+                            Rule::STEP => f.step = expr,
+                            _ => unreachable!(),
+                        }
+                    }
+                    fill = Some(f);
+                }
+                _ => unreachable!(),
+            }
+        }
+        if nulls.is_none() && collate.is_none() && fill.is_none() {
+            return Ok(Self::new(expr, order));
+        }
+        Ok(Self::Ordering {
+            expr,
+            order,
+            nulls,
+            collate,
+            fill,
+        })
     }
 }
 
@@ -162,6 +367,7 @@ parse_sequence!(
 );
 
 // FROM table
+// FROM function(params)
 // FROM (subquery)
 impl FromParsed for queries::from::From {
     fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
@@ -175,14 +381,31 @@ impl FromParsed for queries::from::From {
         let n = parsed.next().unwrap();
 
         let from = match n.as_rule() {
-            Rule::select => {
-                let query = queries::select::Query::parse(n)?;
-                Self::Subquery {
+            Rule::set_op => match queries::Query::parse(n)? {
+                queries::Query::Select(query) => Self::Subquery {
                     query: Box::new(query),
                     alias,
+                },
+                queries::Query::SetOp(query) => Self::SetOp {
+                    query: Box::new(query),
+                    alias,
+                },
+                _ => unreachable!(),
+            },
+            Rule::identifier => Self::Table(n.as_str().into()).with_alias(alias),
+            Rule::function => {
+                let mut n = n.into_inner();
+                let r#fn = n.next().unwrap().as_str().into();
+                let params = match n.next() {
+                    Some(exprs) => FromParsed::parse(exprs)?,
+                    None => Default::default(),
+                };
+                Self::Function {
+                    r#fn,
+                    params,
+                    alias,
                 }
             }
-            Rule::identifier => Self::Table(n.as_str().into()).with_alias(alias),
 
             _ => {
                 unreachable!()
@@ -199,16 +422,9 @@ impl FromParsed for queries::join::On {
         assert_eq!(parsed.as_rule(), Rule::join_cond);
         let parsed = parsed.into_inner().next().unwrap();
         match parsed.as_rule() {
-            Rule::using => {
-                let parsed = parsed.into_inner().nth(1).unwrap();
-                assert_eq!(parsed.as_rule(), Rule::identifiers);
-                Ok(Self::Using(
-                    parsed
-                        .into_inner()
-                        .map(|id| id.as_str().to_string())
-                        .collect(),
-                ))
-            }
+            Rule::using => Ok(Self::Using(FromParsed::parse(
+                parsed.into_inner().nth(1).unwrap(),
+            )?)),
             Rule::on => Ok(Self::On(FromParsed::parse(
                 parsed.into_inner().nth(1).unwrap(),
             )?)),
@@ -216,22 +432,238 @@ impl FromParsed for queries::join::On {
         }
     }
 }
-// JOIN table AS table2 ON/USING
+// [GLOBAL] [LEFT|RIGHT|FULL] [OUTER|ANY|ALL|ASOF|SEMI|ANTI] JOIN table AS table2 ON/USING
 // JOIN (subquery) ON/USING
+// CROSS JOIN table
 impl FromParsed for queries::join::Join {
     fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
         assert_eq!(parsed.as_rule(), Rule::join);
-        let p = parsed.into_inner();
-        let from = queries::from::From::parse(p.find_first_tagged("from").unwrap())?;
-        let on = queries::join::On::parse(p.find_first_tagged("cond").unwrap())?;
+        let mut join = Self {
+            from: queries::from::From::Table(Default::default()),
+            // CROSS JOIN
+            on: queries::join::On::Using(Default::default()),
+            kind: Default::default(),
+            strictness: None,
+            global: false,
+        };
+        for p in parsed.into_inner() {
+            match p.as_rule() {
+                Rule::GLOBAL => join.global = true,
+                Rule::join_kind => {
+                    join.kind = match p.into_inner().next().unwrap().as_rule() {
+                        Rule::INNER | Rule::CROSS => queries::join::Kind::Inner,
+                        Rule::LEFT => queries::join::Kind::LeftOuter,
+                        Rule::RIGHT => queries::join::Kind::RightOuter,
+                        Rule::FULL => queries::join::Kind::FullOuter,
+                        _ => unreachable!(),
+                    }
+                }
+                Rule::join_strictness => {
+                    join.strictness = match p.into_inner().next().unwrap().as_rule() {
+                        Rule::OUTER => None,
+                        Rule::JOIN_ANY => Some(queries::join::Strictness::Any),
+                        Rule::ALL => Some(queries::join::Strictness::All),
+                        Rule::ASOF => Some(queries::join::Strictness::Asof),
+                        Rule::SEMI => Some(queries::join::Strictness::Semi),
+                        Rule::ANTI => Some(queries::join::Strictness::Anti),
+                        _ => unreachable!(),
+                    }
+                }
+                Rule::table_or_subquery => join.from = FromParsed::parse(p)?,
+                Rule::join_cond => join.on = FromParsed::parse(p)?,
+                _ => {}
+            }
+        }
+        Ok(join)
+    }
+}
+// UNBOUNDED PRECEDING, 1 PRECEDING, CURRENT ROW, ...
+impl FromParsed for queries::window::Bound {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::frame_bound);
+        let mut parsed = parsed.into_inner();
+        let first = parsed.next().unwrap();
+        Ok(match first.as_rule() {
+            Rule::UNBOUNDED_PRECEDING => Self::UnboundedPreceding,
+            Rule::UNBOUNDED_FOLLOWING => Self::UnboundedFollowing,
+            Rule::CURRENT_ROW => Self::CurrentRow,
+            Rule::expr => {
+                let expr = FromParsed::parse(first)?;
+                match parsed.next().unwrap().as_rule() {
+                    Rule::PRECEDING => Self::Preceding(expr),
+                    Rule::FOLLOWING => Self::Following(expr),
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        })
+    }
+}
+// ROWS BETWEEN start AND end
+impl FromParsed for queries::window::Frame {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::frame);
+        let mut units = Default::default();
+        let mut bounds = vec![];
+        for p in parsed.into_inner() {
+            match p.as_rule() {
+                Rule::frame_units => {
+                    units = match p.into_inner().next().unwrap().as_rule() {
+                        Rule::ROWS => queries::window::Units::Rows,
+                        Rule::RANGE => queries::window::Units::Range,
+                        _ => unreachable!(),
+                    }
+                }
+                Rule::frame_bound => bounds.push(FromParsed::parse(p)?),
+                _ => {}
+            }
+        }
+        let mut bounds = bounds.into_iter();
         Ok(Self {
-            from,
-            on,
-            // TODO: Support other kinds
-            kind: queries::join::Kind::Inner,
+            units,
+            start: bounds.next().unwrap(),
+            end: bounds.next(),
         })
     }
 }
+// PARTITION BY a ORDER BY b ROWS ...
+impl FromParsed for queries::window::Window {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::window);
+        let mut window = Self::default();
+        for p in parsed.into_inner() {
+            match p.as_rule() {
+                Rule::partition_by => {
+                    window.partition_by = FromParsed::parse(p.into_inner().nth(1).unwrap())?;
+                }
+                Rule::order_by => {
+                    window.order_by = FromParsed::parse(p.into_inner().nth(1).unwrap())?;
+                }
+                Rule::frame => window.frame = Some(FromParsed::parse(p)?),
+                _ => unreachable!(),
+            }
+        }
+        Ok(window)
+    }
+}
+// GROUP BY a, b WITH ROLLUP WITH TOTALS
+// GROUP BY GROUPING SETS ((a, b), (a))
+impl FromParsed for queries::group_by::GroupBy {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::group_by);
+        let mut exprs = Default::default();
+        let mut grouping_sets = vec![];
+        let mut with = None;
+        let mut totals = false;
+        for p in parsed.into_inner() {
+            match p.as_rule() {
+                Rule::exprs => exprs = FromParsed::parse(p)?,
+                Rule::grouping_sets => {
+                    grouping_sets = p
+                        .into_inner()
+                        .filter(|p| p.as_rule() == Rule::grouping_set)
+                        .map(|p| match p.into_inner().next() {
+                            Some(exprs) => FromParsed::parse(exprs),
+                            None => Ok(Default::default()),
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                Rule::group_by_modifier => {
+                    with = Some(match p.into_inner().next().unwrap().as_rule() {
+                        Rule::WITH_ROLLUP => queries::group_by::Modifier::Rollup,
+                        Rule::WITH_CUBE => queries::group_by::Modifier::Cube,
+                        _ => unreachable!(),
+                    });
+                }
+                Rule::WITH_TOTALS => totals = true,
+                _ => {}
+            }
+        }
+        Ok(Self::new(exprs, grouping_sets, with, totals))
+    }
+}
+// 0.1 or 1/10
+impl FromParsed for queries::sample::Ratio {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::sample_ratio);
+        let mut parsed = parsed.into_inner();
+        let first = parsed.next().unwrap();
+        Ok(match first.as_rule() {
+            Rule::decimal => Self::Decimal(first.as_str().parse::<f64>().unwrap().into()),
+            Rule::number => Self::Fraction(
+                first.as_str().parse().unwrap(),
+                parsed.next().unwrap().as_str().parse().unwrap(),
+            ),
+            _ => unreachable!(),
+        })
+    }
+}
+// SAMPLE 10000
+// SAMPLE 1/10 OFFSET 1/2
+impl FromParsed for queries::sample::Sample {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::sample);
+        let mut parsed = parsed.into_inner().skip(1);
+        let first = parsed.next().unwrap();
+        if first.as_rule() == Rule::number {
+            return Ok(Self::Rows(first.as_str().parse().unwrap()));
+        }
+        let ratio = FromParsed::parse(first)?;
+        Ok(match parsed.last() {
+            Some(offset) => Self::Offset {
+                ratio,
+                offset: FromParsed::parse(offset)?,
+            },
+            None => Self::Ratio(ratio),
+        })
+    }
+}
+// SETTINGS name = value, ...
+impl FromParsed for queries::settings::Settings {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert!([Rule::settings, Rule::explain_settings].contains(&parsed.as_rule()));
+        let mut map = vec![];
+        for p in parsed.into_inner().filter(|p| p.as_rule() == Rule::setting) {
+            let mut p = p.into_inner();
+            let name = p.next().unwrap().as_str().to_string();
+            let value = p.next().unwrap().into_inner().next().unwrap();
+            let value = match value.as_rule() {
+                Rule::string => queries::settings::Value::String(unquote(value.as_str())),
+                // Bare identifiers are equivalent to strings
+                Rule::simple_identifier => queries::settings::Value::String(value.as_str().into()),
+                Rule::signed_float => {
+                    queries::settings::Value::Float(value.as_str().parse::<f64>().unwrap().into())
+                }
+                Rule::signed_number => {
+                    queries::settings::Value::Integer(value.as_str().parse().unwrap())
+                }
+                Rule::boolean => {
+                    queries::settings::Value::Bool(value.as_str().eq_ignore_ascii_case("true"))
+                }
+                _ => unreachable!(),
+            };
+            map.push((name, value));
+        }
+        Ok(Self::Map(map))
+    }
+}
+// name AS (subquery)
+// expr AS name
+impl FromParsed for queries::with::With {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::cte);
+        let mut parsed = parsed.into_inner();
+        let first = parsed.next().unwrap();
+        match first.as_rule() {
+            Rule::identifier => Ok(Self::Subquery {
+                name: first.as_str().into(),
+                query: Box::new(queries::Query::parse(parsed.last().unwrap())?),
+            }),
+            Rule::expr => Ok(Self::Expr(FromParsed::parse(first)?)),
+            _ => unreachable!(),
+        }
+    }
+}
 fn parse_first_tagged<T: std::str::FromStr>(p: Pair<Rule>, tag: &str) -> T
 where
     T::Err: std::fmt::Debug,
@@ -243,36 +675,99 @@ where
         .parse()
         .unwrap()
 }
+// Error located at the start of the pair
+fn error(reason: &str, p: &Pair<Rule>) -> SQLParseError {
+    SQLParseError {
+        reason: reason.into(),
+        src: miette::NamedSource::new("source.sql", p.get_input().into()),
+        span: p.as_span().start().into(),
+    }
+}
+// LIMIT [offset, ]limit or LIMIT limit OFFSET offset, returning (limit, offset)
+fn parse_limit(p: &Pair<Rule>) -> (usize, Option<usize>) {
+    let mut limit = 0;
+    let mut offset = None;
+    for p in p.clone().into_inner() {
+        match p.as_node_tag() {
+            Some("limit") => limit = p.as_str().parse().unwrap(),
+            Some("offset") => offset = Some(p.as_str().parse().unwrap()),
+            _ => {}
+        }
+    }
+    (limit, offset)
+}
 impl FromParsed for queries::select::Query {
     fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
         assert_eq!(parsed.as_rule(), Rule::select);
         let mut query = queries::select::Query::default();
-        for p in parsed.into_inner() {
+        for p in parsed.clone().into_inner() {
             let rule = p.as_rule();
             fn parse_inner<T: FromParsed>(p: Pair<Rule>) -> Result<T, SQLParseError> {
                 FromParsed::parse(p.into_inner().nth(1).unwrap())
             }
             match rule {
+                Rule::with => {
+                    query.with = p
+                        .into_inner()
+                        .skip(1)
+                        .map(FromParsed::parse)
+                        .collect::<Result<_, _>>()?;
+                }
+                Rule::distinct => {
+                    query.distinct = match p.into_inner().find(|p| p.as_rule() == Rule::exprs) {
+                        Some(exprs) => queries::select::Distinct::On(FromParsed::parse(exprs)?),
+                        None => queries::select::Distinct::Bool(true),
+                    };
+                }
                 Rule::fields => {
                     query.fields = Some(FromParsed::parse(p.into_inner().next().unwrap())?);
                 }
                 Rule::table_or_subquery => {
                     query.from = Some(FromParsed::parse(p)?);
                 }
+                Rule::FINAL => query.final_ = true,
+                Rule::limit_by => {
+                    let (limit, offset) = parse_limit(&p);
+                    let by = FromParsed::parse(p.into_inner().last().unwrap())?;
+                    query.limit_by = Some(queries::limit::LimitBy::Full { limit, offset, by });
+                }
                 Rule::limit => {
-                    query.limit = Some(parse_first_tagged(p, "limit"));
+                    let (limit, offset) = parse_limit(&p);
+                    query.limit = Some(queries::limit::Limit::new(limit, offset, false));
+                }
+                Rule::WITH_TIES => {
+                    let Some(limit) = query.limit.take() else {
+                        return Err(error("WITH TIES requires LIMIT", &p));
+                    };
+                    // LIMIT n OFFSET m WITH TIES is equivalent to LIMIT m, n WITH TIES
+                    let offset = match limit.offset() {
+                        None => query.offset.take(),
+                        offset => offset,
+                    };
+                    query.limit = Some(queries::limit::Limit::new(limit.rows(), offset, true));
                 }
-                Rule::sample => query.sample = Some(parse_first_tagged(p, "sample")),
+                Rule::sample => query.sample = Some(FromParsed::parse(p)?),
                 Rule::offset => query.offset = Some(parse_first_tagged(p, "offset")),
                 Rule::order_by => {
                     query.order_by = parse_inner(p)?;
                 }
                 Rule::group_by => {
-                    query.group_by = parse_inner(p)?;
+                    query.group_by = FromParsed::parse(p)?;
+                }
+                Rule::array_join => {
+                    let mut p = p.into_inner();
+                    let left = p.next().unwrap().as_rule() == Rule::LEFT_ARRAY_JOIN;
+                    let exprs = FromParsed::parse(p.next().unwrap())?;
+                    query
+                        .array_join
+                        .push(queries::array_join::ArrayJoin { exprs, left });
                 }
                 Rule::join => {
                     query.joins.push(FromParsed::parse(p)?);
                 }
+                Rule::prewhere => {
+                    query.prewhere = Some(parse_inner(p)?);
+                }
                 Rule::r#where => {
                     query.where_ = Some(parse_inner(p)?);
                 }
@@ -280,14 +775,114 @@ impl FromParsed for queries::select::Query {
                     query.having = Some(parse_inner(p)?);
                 }
                 Rule::settings => {
-                    query.settings = parse_inner(p)?;
+                    query.settings = FromParsed::parse(p)?;
+                }
+                Rule::into_outfile => {
+                    query.into_outfile = Some(unquote(p.into_inner().nth(1).unwrap().as_str()));
                 }
+                Rule::format => query.format = Some(p.into_inner().nth(1).unwrap().as_str().into()),
                 _ => {}
             }
         }
+        if query.offset.is_some() && query.limit.as_ref().is_some_and(|l| l.offset().is_some()) {
+            return Err(error("OFFSET cannot follow LIMIT with an offset", &parsed));
+        }
         Ok(query)
     }
 }
+// EXPLAIN kind setting = value, ... query
+impl FromParsed for queries::explain::Query {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::explain);
+        let mut kind = Default::default();
+        let mut settings = Default::default();
+        let mut query = None;
+        for p in parsed.into_inner() {
+            match p.as_rule() {
+                Rule::explain_kind => kind = FromParsed::parse(p)?,
+                Rule::explain_settings => settings = FromParsed::parse(p)?,
+                Rule::insert | Rule::set_op => query = Some(Box::new(FromParsed::parse(p)?)),
+                _ => {}
+            }
+        }
+        Ok(Self {
+            kind,
+            settings,
+            query: query.unwrap(),
+        })
+    }
+}
+impl FromParsed for queries::explain::Kind {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::explain_kind);
+        Ok(match parsed.as_str().to_lowercase().as_str() {
+            "plan" => Self::Plan,
+            "pipeline" => Self::Pipeline,
+            "syntax" => Self::Syntax,
+            "estimate" => Self::Estimate,
+            "ast" => Self::Ast,
+            _ => unreachable!(),
+        })
+    }
+}
+// INSERT INTO table (col1, col2) SELECT ...
+impl FromParsed for queries::insert::Query {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::insert);
+        let mut into = String::new();
+        let mut columns = vec![];
+        let mut select = None;
+        for p in parsed.into_inner() {
+            match p.as_rule() {
+                Rule::identifier => into = p.as_str().into(),
+                Rule::identifiers => {
+                    columns = p.into_inner().map(|id| id.as_str().to_string()).collect();
+                }
+                Rule::select => select = Some(FromParsed::parse(p)?),
+                _ => {}
+            }
+        }
+        Ok(Self {
+            into,
+            columns,
+            select: select.unwrap(),
+        })
+    }
+}
+// SELECT ... UNION ALL SELECT ...
+impl FromParsed for queries::set_op::Query {
+    fn parse(parsed: Pair<Rule>) -> Result<Self, SQLParseError> {
+        assert_eq!(parsed.as_rule(), Rule::set_op);
+        let mut kind = None;
+        let mut queries = vec![];
+        for p in parsed.clone().into_inner() {
+            match p.as_rule() {
+                Rule::select => queries.push(FromParsed::parse(p)?),
+                Rule::set_op_kind => {
+                    let k = match p.into_inner().next().unwrap().as_rule() {
+                        Rule::UNION_ALL => queries::set_op::Kind::UnionAll,
+                        Rule::UNION_DISTINCT => queries::set_op::Kind::UnionDistinct,
+                        Rule::INTERSECT => queries::set_op::Kind::Intersect,
+                        Rule::EXCEPT => queries::set_op::Kind::Except,
+                        _ => unreachable!(),
+                    };
+                    if kind.is_some_and(|kind| kind != k) {
+                        return Err(error(
+                            "Mixing different set operators is not supported",
+                            &parsed,
+                        ));
+                    }
+                    kind = Some(k);
+                }
+                _ => {}
+            }
+        }
+        Ok(Self {
+            kind: kind.unwrap_or_default(),
+            queries,
+        })
+    }
+}
 
 pub(super) fn query_from_sql<T: FromParsed>(sql: &str, rule: Rule) -> Result<T, SQLParseError> {
     let parsed = SQLParser::parse(rule, sql)
@@ -27,12 +27,20 @@ impl<'de> serde::de::Visitor<'de> for Visitor {
             let Some(key) = map.next_key::<String>()? else {
                 break;
             };
-            if key == "select" {
-                let value = map.next_value::<select::Query>()?;
-                self.0 .0.push(Query::Select(value));
-            } else {
-                return Err(A::Error::custom(format!("Unsupported query type {}", key)));
-            }
+            let query = match key.as_str() {
+                "select" => Query::Select(map.next_value()?),
+                "insert" => Query::Insert(map.next_value()?),
+                "setOp" => Query::SetOp(map.next_value()?),
+                "createTable" => Query::CreateTable(map.next_value()?),
+                "createView" => Query::CreateView(map.next_value()?),
+                "createMaterializedView" => Query::CreateMaterializedView(map.next_value()?),
+                "alter" => Query::Alter(map.next_value()?),
+                "delete" => Query::Delete(map.next_value()?),
+                "explain" => Query::Explain(map.next_value()?),
+                "raw" => Query::Raw(map.next_value()?),
+                _ => return Err(A::Error::custom(format!("Unsupported query type {}", key))),
+            };
+            self.0 .0.push(query);
         }
         Ok(self.0)
     }
@@ -93,6 +101,7 @@ impl<'a> IntoIterator for &'a Queries {
 /// SQL expressions
 pub mod expr {
     use super::*;
+    use std::collections::BTreeMap;
 
     #[derive(Eq, PartialEq, Debug, Deserialize, Serialize)]
     pub struct Prefix(pub String);
@@ -112,6 +121,11 @@ pub mod expr {
             write!(f, "{}", self.0)
         }
     }
+    impl From<f64> for FloatEq {
+        fn from(source: f64) -> Self {
+            Self(source)
+        }
+    }
     impl std::cmp::PartialEq for FloatEq {
         fn eq(&self, other: &Self) -> bool {
             self.0.to_le_bytes() == other.0.to_le_bytes()
@@ -125,14 +139,83 @@ pub mod expr {
         RawBool(bool),
         RawInteger(i64),
         RawFloat(FloatEq),
+        Null,
         Prefix(Prefix, Box<Expr>),
         // [expr, op, [expr]]
         Operator(Box<Expr>, Operator, Box<Expr>),
         // [expr, [[op, expr], ...]]
         OperatorSeq(Box<Expr>, Vec<(Operator, Box<Expr>)>),
+        // expr IS [NOT] NULL
+        IsNull {
+            #[serde(rename = "isNull")]
+            expr: Box<Expr>,
+            #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+            not: bool,
+        },
+        // expr [NOT] BETWEEN low AND high
+        Between {
+            #[serde(rename = "between")]
+            expr: Box<Expr>,
+            low: Box<Expr>,
+            high: Box<Expr>,
+            #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+            not: bool,
+        },
         Subquery(Box<Query>),
-        FunctionCall { r#fn: String, params: ExprList },
-        Aliased { expr: Box<Expr>, alias: String },
+        // 'string', escaped when printed
+        String {
+            string: String,
+        },
+        // [a, b, ...]
+        Array {
+            array: ExprList,
+        },
+        // (a, b, ...)
+        Tuple {
+            tuple: ExprList,
+        },
+        // map('key', value, ...)
+        Map {
+            map: BTreeMap<String, Expr>,
+        },
+        // CAST(expr AS type)
+        Cast {
+            cast: Box<Expr>,
+            r#type: String,
+        },
+        // Query parameter {name:type}, bound by Clickhouse from the `param_name` URL parameter
+        Param {
+            #[serde(rename = "param")]
+            name: String,
+            r#type: String,
+        },
+        FunctionCall {
+            r#fn: String,
+            params: ExprList,
+        },
+        // fn(params) OVER (window)
+        Window {
+            r#fn: String,
+            params: ExprList,
+            over: Box<window::Window>,
+        },
+        // x -> body or (x, y) -> body
+        Lambda {
+            lambda: Vec<String>,
+            body: Box<Expr>,
+        },
+        // CASE [operand] WHEN cond THEN result ... [ELSE expr] END
+        Case {
+            #[serde(rename = "case", default, skip_serializing_if = "Option::is_none")]
+            operand: Option<Box<Expr>>,
+            when: Vec<(Expr, Expr)>,
+            #[serde(rename = "else", default, skip_serializing_if = "Option::is_none")]
+            else_: Option<Box<Expr>>,
+        },
+        Aliased {
+            expr: Box<Expr>,
+            alias: String,
+        },
     }
     impl From<&str> for Expr {
         fn from(source: &str) -> Self {
@@ -148,7 +231,19 @@ pub mod expr {
 
     impl Expr {
         pub fn is_raw(&self) -> bool {
-            matches!(self, Self::Raw(_) | Self::RawBool(_) | Self::RawInteger(_))
+            matches!(
+                self,
+                Self::Raw(_)
+                    | Self::RawBool(_)
+                    | Self::RawInteger(_)
+                    | Self::Null
+                    | Self::String { .. }
+                    | Self::Array { .. }
+                    | Self::Tuple { .. }
+                    | Self::Map { .. }
+                    | Self::Cast { .. }
+                    | Self::Param { .. }
+            )
         }
         pub fn operator(self, op: Operator, right: Expr) -> Self {
             Self::Operator(Box::new(self), op, Box::new(right))
@@ -175,6 +270,20 @@ pub mod expr {
 #[serde(deny_unknown_fields, rename_all = "lowercase")]
 pub enum Query {
     Select(select::Query),
+    Insert(insert::Query),
+    #[serde(rename = "setOp")]
+    SetOp(set_op::Query),
+    #[serde(rename = "createTable")]
+    CreateTable(create::Table),
+    #[serde(rename = "createView")]
+    CreateView(create::View),
+    #[serde(rename = "createMaterializedView")]
+    CreateMaterializedView(create::MaterializedView),
+    Alter(alter::Query),
+    Delete(delete::Query),
+    Explain(explain::Query),
+    // Unsupported statement, passed through verbatim
+    Raw(String),
 }
 
 /// `FROM` statements
@@ -189,6 +298,19 @@ pub mod from {
             #[serde(rename = "as")]
             alias: String,
         },
+        // Table function or parameterized view, e.g. numbers(100)
+        Function {
+            r#fn: String,
+            params: ExprList,
+            #[serde(rename = "as", default, skip_serializing_if = "Option::is_none")]
+            alias: Option<String>,
+        },
+        SetOp {
+            #[serde(rename = "setOp")]
+            query: Box<set_op::Query>,
+            #[serde(rename = "as")]
+            alias: Option<String>,
+        },
         Subquery {
             #[serde(flatten)]
             query: Box<select::Query>,
@@ -204,6 +326,15 @@ pub mod from {
             match self {
                 Self::Table(table) => Self::AliasedTable { table, alias },
                 Self::AliasedTable { table, .. } => Self::AliasedTable { table, alias },
+                Self::Function { r#fn, params, .. } => Self::Function {
+                    r#fn,
+                    params,
+                    alias: Some(alias),
+                },
+                Self::SetOp { query, .. } => Self::SetOp {
+                    query,
+                    alias: Some(alias),
+                },
                 Self::Subquery { query, .. } => Self::Subquery {
                     query,
                     alias: Some(alias),
@@ -217,13 +348,64 @@ pub mod from {
 pub mod join {
     use super::*;
     #[derive(Deserialize, Serialize, PartialEq, Eq, Debug)]
-    #[serde(deny_unknown_fields)]
+    #[serde(deny_unknown_fields, remote = "Self")]
     pub struct Join {
         pub from: from::From,
         #[serde(flatten)]
         pub on: On,
         #[serde(default)]
         pub kind: Kind,
+        // ANY, ALL, ASOF, SEMI or ANTI
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub strictness: Option<Strictness>,
+        // GLOBAL JOIN, for distributed tables
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        pub global: bool,
+    }
+    impl<'de> Deserialize<'de> for Join {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let join = Self::deserialize(deserializer)?;
+            match (join.strictness, join.kind) {
+                (Some(Strictness::Semi | Strictness::Anti), Kind::Inner | Kind::FullOuter) => Err(
+                    D::Error::custom("SEMI and ANTI joins must be left-outer or right-outer"),
+                ),
+                (Some(Strictness::Asof), Kind::RightOuter | Kind::FullOuter) => {
+                    Err(D::Error::custom("ASOF joins must be inner or left-outer"))
+                }
+                _ => Ok(join),
+            }
+        }
+    }
+    impl Serialize for Join {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            Self::serialize(self, serializer)
+        }
+    }
+    impl Join {
+        pub fn name(&self) -> String {
+            let mut name = vec![];
+            if self.global {
+                name.push("GLOBAL");
+            }
+            if self.on.is_empty() {
+                name.push("CROSS");
+            } else {
+                name.extend(self.kind.direction());
+                match self.strictness {
+                    Some(strictness) => name.push(strictness.name()),
+                    None if self.kind != Kind::Inner => name.push("OUTER"),
+                    None => {}
+                }
+            }
+            name.push("JOIN");
+            name.join(" ")
+        }
     }
 
     #[derive(Default, Deserialize, Serialize, PartialEq, Eq, Debug, Copy, Clone)]
@@ -244,22 +426,206 @@ pub mod join {
                 Kind::FullOuter => "FULL OUTER JOIN",
             }
         }
+        pub fn direction(&self) -> Option<&'static str> {
+            match self {
+                Kind::Inner => None,
+                Kind::LeftOuter => Some("LEFT"),
+                Kind::RightOuter => Some("RIGHT"),
+                Kind::FullOuter => Some("FULL"),
+            }
+        }
+    }
+
+    #[derive(Deserialize, Serialize, PartialEq, Eq, Debug, Copy, Clone)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum Strictness {
+        Any,
+        All,
+        Asof,
+        Semi,
+        Anti,
+    }
+    impl Strictness {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Strictness::Any => "ANY",
+                Strictness::All => "ALL",
+                Strictness::Asof => "ASOF",
+                Strictness::Semi => "SEMI",
+                Strictness::Anti => "ANTI",
+            }
+        }
     }
 
     #[serde_with::serde_as]
     #[derive(Deserialize, Serialize, PartialEq, Eq, Debug)]
-    #[serde(deny_unknown_fields)]
+    #[serde(deny_unknown_fields, try_from = "OnList")]
     pub enum On {
-        #[serde(rename = "on")]
-        On(ExprList),
+        #[serde(rename = "on", serialize_with = "serialize_on")]
+        On(Expr),
+        // Columns, possibly aliased
         #[serde(rename = "using")]
-        Using(Vec<String>),
+        Using(ExprList),
     }
     impl On {
+        // CROSS JOIN
+        pub fn is_empty(&self) -> bool {
+            matches!(self, On::Using(cols) if cols.is_empty())
+        }
+        // AND-combines conditions, with an empty list resulting in a CROSS JOIN
+        pub fn and(conditions: Vec<Expr>) -> Self {
+            let mut conditions = conditions.into_iter();
+            let Some(first) = conditions.next() else {
+                return Self::Using(Default::default());
+            };
+            let rest: Vec<_> = conditions
+                .map(|c| (Operator("AND".into()), Box::new(c)))
+                .collect();
+            if rest.is_empty() {
+                Self::On(first)
+            } else {
+                Self::On(Expr::OperatorSeq(Box::new(first), rest))
+            }
+        }
+    }
+
+    // Operator expressions are arrays, which would be read back as a list of conditions.
+    fn serialize_on<S: serde::Serializer>(expr: &Expr, serializer: S) -> Result<S::Ok, S::Error> {
+        [expr].serialize(serializer)
+    }
+
+    // `on` is either a list of conditions or a single non-array condition.
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    enum OnList {
+        #[serde(rename = "on")]
+        On(Conditions),
+        #[serde(rename = "using")]
+        Using(ExprList),
+    }
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Conditions {
+        List(Vec<Expr>),
+        Expr(Expr),
+    }
+    impl std::convert::TryFrom<OnList> for On {
+        type Error = String;
+        fn try_from(source: OnList) -> Result<Self, Self::Error> {
+            let conditions = match source {
+                OnList::On(Conditions::Expr(
+                    Expr::Prefix(..) | Expr::Operator(..) | Expr::OperatorSeq(..),
+                )) => {
+                    return Err("Join conditions given as arrays must be wrapped in a list".into());
+                }
+                OnList::On(Conditions::Expr(expr)) => vec![expr],
+                OnList::On(Conditions::List(conditions)) => conditions,
+                OnList::Using(cols) => return Ok(Self::Using(cols)),
+            };
+            if conditions
+                .iter()
+                .any(|c| matches!(c, Expr::Raw(s) if s.trim().is_empty()))
+            {
+                return Err("Empty join condition".into());
+            }
+            Ok(Self::and(conditions))
+        }
+    }
+}
+
+/// `ARRAY JOIN` statements
+pub mod array_join {
+    use super::*;
+    #[derive(Deserialize, Serialize, PartialEq, Eq, Debug)]
+    #[serde(deny_unknown_fields)]
+    pub struct ArrayJoin {
+        pub exprs: ExprList,
+        // LEFT ARRAY JOIN
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        pub left: bool,
+    }
+    impl ArrayJoin {
+        pub fn name(&self) -> &'static str {
+            if self.left {
+                "LEFT ARRAY JOIN"
+            } else {
+                "ARRAY JOIN"
+            }
+        }
+    }
+}
+
+/// `GROUP BY` statements
+pub mod group_by {
+    use super::*;
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, untagged)]
+    pub enum GroupBy {
+        Exprs(ExprList),
+        Modifiers {
+            #[serde(default, skip_serializing_if = "ExprList::is_empty")]
+            exprs: ExprList,
+            #[serde(
+                default,
+                rename = "groupingSets",
+                skip_serializing_if = "Vec::is_empty"
+            )]
+            grouping_sets: Vec<ExprList>,
+            // WITH ROLLUP or WITH CUBE
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            with: Option<Modifier>,
+            // WITH TOTALS
+            #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+            totals: bool,
+        },
+    }
+    impl GroupBy {
+        pub fn new(
+            exprs: ExprList,
+            grouping_sets: Vec<ExprList>,
+            with: Option<Modifier>,
+            totals: bool,
+        ) -> Self {
+            if grouping_sets.is_empty() && with.is_none() && !totals {
+                Self::Exprs(exprs)
+            } else {
+                Self::Modifiers {
+                    exprs,
+                    grouping_sets,
+                    with,
+                    totals,
+                }
+            }
+        }
         pub fn is_empty(&self) -> bool {
             match self {
-                On::On(exprs) => exprs.is_empty(),
-                On::Using(cols) => cols.is_empty(),
+                Self::Exprs(exprs) => exprs.is_empty(),
+                Self::Modifiers {
+                    exprs,
+                    grouping_sets,
+                    ..
+                } => exprs.is_empty() && grouping_sets.is_empty(),
+            }
+        }
+    }
+    impl Default for GroupBy {
+        fn default() -> Self {
+            Self::Exprs(Default::default())
+        }
+    }
+
+    #[derive(Deserialize, Serialize, PartialEq, Eq, Debug, Copy, Clone)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Modifier {
+        Rollup,
+        Cube,
+    }
+    impl Modifier {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Modifier::Rollup => "WITH ROLLUP",
+                Modifier::Cube => "WITH CUBE",
             }
         }
     }
@@ -273,14 +639,30 @@ pub mod order_by {
     #[serde(deny_unknown_fields, untagged)]
     pub enum Expr {
         Asc(super::Expr),
-        Ordering { expr: super::Expr, order: Ordering },
+        Ordering {
+            expr: super::Expr,
+            #[serde(default)]
+            order: Ordering,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            nulls: Option<Nulls>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            collate: Option<String>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            fill: Option<Fill>,
+        },
     }
     impl Expr {
         pub fn new(expr: super::Expr, order: Ordering) -> Self {
             if order == Ordering::Asc {
                 Self::Asc(expr)
             } else {
-                Self::Ordering { expr, order }
+                Self::Ordering {
+                    expr,
+                    order,
+                    nulls: None,
+                    collate: None,
+                    fill: None,
+                }
             }
         }
     }
@@ -292,23 +674,270 @@ pub mod order_by {
         #[serde(rename = "desc")]
         Desc,
     }
+    // NULLS FIRST or NULLS LAST
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Copy, Clone)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Nulls {
+        First,
+        Last,
+    }
+    impl Nulls {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Nulls::First => "NULLS FIRST",
+                Nulls::Last => "NULLS LAST",
+            }
+        }
+    }
+    // WITH FILL FROM from TO to STEP step
+    #[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Fill {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub from: Option<super::Expr>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub to: Option<super::Expr>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub step: Option<super::Expr>,
+    }
+}
+
+/// Window specifications (`OVER (...)`)
+pub mod window {
+    use super::*;
+    #[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Window {
+        #[serde(
+            default,
+            rename = "partitionBy",
+            skip_serializing_if = "ExprList::is_empty"
+        )]
+        pub partition_by: ExprList,
+        #[serde(default, rename = "orderBy", skip_serializing_if = "Vec::is_empty")]
+        pub order_by: Vec<order_by::Expr>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub frame: Option<Frame>,
+    }
+
+    // ROWS BETWEEN start AND end
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Frame {
+        #[serde(default)]
+        pub units: Units,
+        pub start: Bound,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub end: Option<Bound>,
+    }
+
+    #[derive(Default, Deserialize, Serialize, PartialEq, Eq, Debug, Copy, Clone)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Units {
+        #[default]
+        Rows,
+        Range,
+    }
+    impl Units {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Units::Rows => "ROWS",
+                Units::Range => "RANGE",
+            }
+        }
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, rename_all = "kebab-case")]
+    pub enum Bound {
+        UnboundedPreceding,
+        Preceding(Expr),
+        CurrentRow,
+        Following(Expr),
+        UnboundedFollowing,
+    }
+}
+
+/// `WITH` statements (common table expressions)
+pub mod with {
+    use super::*;
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, untagged)]
+    pub enum With {
+        // name AS (subquery)
+        Subquery {
+            name: String,
+            #[serde(flatten)]
+            query: Box<Query>,
+        },
+        // expr AS name
+        Expr(Expr),
+    }
+}
+
+/// `LIMIT` and `LIMIT BY` statements
+pub mod limit {
+    use super::*;
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, untagged)]
+    pub enum Limit {
+        Rows(usize),
+        Full {
+            limit: usize,
+            // LIMIT offset, limit
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            offset: Option<usize>,
+            #[serde(
+                default,
+                rename = "withTies",
+                skip_serializing_if = "std::ops::Not::not"
+            )]
+            with_ties: bool,
+        },
+    }
+    impl Limit {
+        pub fn new(limit: usize, offset: Option<usize>, with_ties: bool) -> Self {
+            if offset.is_none() && !with_ties {
+                Self::Rows(limit)
+            } else {
+                Self::Full {
+                    limit,
+                    offset,
+                    with_ties,
+                }
+            }
+        }
+        pub fn rows(&self) -> usize {
+            match self {
+                Self::Rows(limit) | Self::Full { limit, .. } => *limit,
+            }
+        }
+        pub fn offset(&self) -> Option<usize> {
+            match self {
+                Self::Rows(_) => None,
+                Self::Full { offset, .. } => *offset,
+            }
+        }
+        pub fn with_ties(&self) -> bool {
+            matches!(
+                self,
+                Self::Full {
+                    with_ties: true,
+                    ..
+                }
+            )
+        }
+    }
+    impl From<usize> for Limit {
+        fn from(limit: usize) -> Self {
+            Self::Rows(limit)
+        }
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, untagged)]
+    pub enum LimitBy {
+        // Legacy form, taking the number of rows from the query's `limit`.
+        // Converted to `Full` when deserializing a `select::Query`.
+        Exprs(ExprList),
+        // LIMIT [offset, ]limit BY exprs
+        Full {
+            limit: usize,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            offset: Option<usize>,
+            by: ExprList,
+        },
+    }
+}
+
+/// `SAMPLE` statements
+pub mod sample {
+    use super::*;
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, untagged)]
+    pub enum Sample {
+        // SAMPLE 10000 (approximate number of rows)
+        Rows(usize),
+        // SAMPLE 0.1 or SAMPLE 1/10
+        Ratio(Ratio),
+        // SAMPLE 1/10 OFFSET 1/2
+        Offset { ratio: Ratio, offset: Ratio },
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, untagged)]
+    pub enum Ratio {
+        Decimal(FloatEq),
+        // [numerator, denominator]
+        Fraction(u64, u64),
+    }
+}
+
+/// `SETTINGS` statements
+pub mod settings {
+    use super::*;
+
+    #[serde_with::serde_as]
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, untagged)]
+    pub enum Settings {
+        // ["max_threads = 8"]
+        Exprs(ExprList),
+        // { max_threads: 8 }, keeping the input order
+        Map(#[serde_as(as = "serde_with::Map<_, _>")] Vec<(String, Value)>),
+    }
+    impl Settings {
+        pub fn is_empty(&self) -> bool {
+            match self {
+                Self::Exprs(exprs) => exprs.is_empty(),
+                Self::Map(map) => map.is_empty(),
+            }
+        }
+    }
+    impl Default for Settings {
+        fn default() -> Self {
+            Self::Exprs(Default::default())
+        }
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(untagged)]
+    pub enum Value {
+        Bool(bool),
+        Integer(i64),
+        Float(FloatEq),
+        String(String),
+    }
 }
 
 /// `SELECT` queries
 pub mod select {
     use super::*;
     #[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
-    #[serde(deny_unknown_fields)]
+    #[serde(deny_unknown_fields, remote = "Self")]
     pub struct Query {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub with: Vec<with::With>,
+        #[serde(default, skip_serializing_if = "Distinct::is_false")]
+        pub distinct: Distinct,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub fields: Option<ExprList>,
         pub from: Option<from::From>,
+        // FROM table FINAL
+        #[serde(default, rename = "final", skip_serializing_if = "std::ops::Not::not")]
+        pub final_: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub prewhere: Option<Expr>,
         #[serde(rename = "where")]
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub where_: Option<Expr>,
         #[serde(rename = "groupBy")]
-        #[serde(default, skip_serializing_if = "ExprList::is_empty")]
-        pub group_by: ExprList,
+        #[serde(default, skip_serializing_if = "group_by::GroupBy::is_empty")]
+        pub group_by: group_by::GroupBy,
+        #[serde(default, rename = "arrayJoin", skip_serializing_if = "Vec::is_empty")]
+        pub array_join: Vec<array_join::ArrayJoin>,
         #[serde(default)]
         #[serde(skip_serializing_if = "Vec::is_empty")]
         pub joins: Vec<join::Join>,
@@ -317,16 +946,352 @@ pub mod select {
         #[serde(default, rename = "orderBy", skip_serializing_if = "Vec::is_empty")]
         pub order_by: Vec<order_by::Expr>,
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub limit: Option<usize>,
+        pub limit: Option<limit::Limit>,
         #[serde(rename = "limitBy", skip_serializing_if = "Option::is_none")]
-        // TODO: Error if `limit` is not set
-        // TODO: Support in from_sql
-        pub limit_by: Option<ExprList>,
+        pub limit_by: Option<limit::LimitBy>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
-        pub sample: Option<usize>,
+        pub sample: Option<sample::Sample>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub offset: Option<usize>,
+        #[serde(default, skip_serializing_if = "settings::Settings::is_empty")]
+        pub settings: settings::Settings,
+        #[serde(
+            default,
+            rename = "intoOutfile",
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub into_outfile: Option<String>,
+        // Output format, e.g. `JSONEachRow`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub format: Option<String>,
+    }
+    // Validates `limitBy` and converts its legacy form, which uses `limit` as the number of rows.
+    impl<'de> Deserialize<'de> for Query {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let mut query = Self::deserialize(deserializer)?;
+            if let Some(limit::LimitBy::Exprs(by)) = &mut query.limit_by {
+                let Some(limit) = query.limit.take() else {
+                    return Err(D::Error::custom("limitBy requires limit to be set"));
+                };
+                query.limit_by = Some(limit::LimitBy::Full {
+                    limit: limit.rows(),
+                    offset: limit.offset(),
+                    by: std::mem::take(by),
+                });
+            }
+            if matches!(&query.distinct, Distinct::On(exprs) if exprs.is_empty()) {
+                return Err(D::Error::custom(
+                    "distinct requires at least one expression",
+                ));
+            }
+            if query.offset.is_some() && query.limit.as_ref().is_some_and(|l| l.offset().is_some())
+            {
+                return Err(D::Error::custom(
+                    "offset cannot be set together with an offset in limit",
+                ));
+            }
+            Ok(query)
+        }
+    }
+    impl Serialize for Query {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            Self::serialize(self, serializer)
+        }
+    }
+
+    // SELECT DISTINCT or SELECT DISTINCT ON (exprs)
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(untagged)]
+    pub enum Distinct {
+        Bool(bool),
+        On(ExprList),
+    }
+    impl Distinct {
+        pub fn is_false(&self) -> bool {
+            matches!(self, Self::Bool(false))
+        }
+    }
+    impl Default for Distinct {
+        fn default() -> Self {
+            Self::Bool(false)
+        }
+    }
+}
+
+/// `INSERT INTO ... SELECT` queries
+pub mod insert {
+    use super::*;
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Query {
+        pub into: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub columns: Vec<String>,
+        pub select: select::Query,
+    }
+}
+
+/// `CREATE TABLE`, `CREATE VIEW` and `CREATE MATERIALIZED VIEW` statements
+pub mod create {
+    use super::*;
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Table {
+        pub name: String,
+        #[serde(
+            default,
+            rename = "ifNotExists",
+            skip_serializing_if = "std::ops::Not::not"
+        )]
+        pub if_not_exists: bool,
+        pub columns: Vec<Column>,
+        #[serde(flatten)]
+        pub storage: Storage,
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Column {
+        pub name: String,
+        pub r#type: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub default: Option<Expr>,
+        // e.g. ['Delta', 'ZSTD(1)']
+        #[serde(default, skip_serializing_if = "ExprList::is_empty")]
+        pub codec: ExprList,
+    }
+
+    /// Table engine and its clauses, shared by tables and materialized views.
+    #[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq)]
+    pub struct Storage {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub engine: Option<Expr>,
+        #[serde(
+            default,
+            rename = "partitionBy",
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub partition_by: Option<Expr>,
+        #[serde(
+            default,
+            rename = "orderBy",
+            skip_serializing_if = "ExprList::is_empty"
+        )]
+        pub order_by: ExprList,
         #[serde(default, skip_serializing_if = "ExprList::is_empty")]
-        pub settings: ExprList,
+        pub ttl: ExprList,
+        #[serde(default, skip_serializing_if = "settings::Settings::is_empty")]
+        pub settings: settings::Settings,
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct View {
+        pub name: String,
+        #[serde(
+            default,
+            rename = "orReplace",
+            skip_serializing_if = "std::ops::Not::not"
+        )]
+        pub or_replace: bool,
+        #[serde(
+            default,
+            rename = "ifNotExists",
+            skip_serializing_if = "std::ops::Not::not"
+        )]
+        pub if_not_exists: bool,
+        pub select: select::Query,
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, remote = "Self")]
+    pub struct MaterializedView {
+        pub name: String,
+        #[serde(
+            default,
+            rename = "ifNotExists",
+            skip_serializing_if = "std::ops::Not::not"
+        )]
+        pub if_not_exists: bool,
+        // Target table, instead of an engine
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub to: Option<String>,
+        #[serde(flatten)]
+        pub storage: Storage,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        pub populate: bool,
+        pub select: select::Query,
+    }
+    impl<'de> Deserialize<'de> for MaterializedView {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let view = Self::deserialize(deserializer)?;
+            if view.to.is_some() && (view.storage != Storage::default() || view.populate) {
+                return Err(D::Error::custom(
+                    "to cannot be combined with an engine or populate",
+                ));
+            }
+            Ok(view)
+        }
+    }
+    impl Serialize for MaterializedView {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            Self::serialize(self, serializer)
+        }
+    }
+}
+
+/// `ALTER TABLE` statements, including mutations
+pub mod alter {
+    use super::*;
+    use std::collections::BTreeMap;
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Query {
+        pub table: String,
+        pub commands: Vec<Command>,
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, rename_all = "camelCase")]
+    pub enum Command {
+        // UPDATE column = expr, ... WHERE expr
+        Update {
+            set: BTreeMap<String, Expr>,
+            #[serde(rename = "where")]
+            where_: Expr,
+        },
+        // DELETE WHERE expr
+        Delete {
+            #[serde(rename = "where")]
+            where_: Expr,
+        },
+        AddColumn(create::Column),
+        DropColumn(String),
+        ModifyColumn(create::Column),
+    }
+    impl Command {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Self::Update { .. } => "UPDATE",
+                Self::Delete { .. } => "DELETE",
+                Self::AddColumn(_) => "ADD COLUMN",
+                Self::DropColumn(_) => "DROP COLUMN",
+                Self::ModifyColumn(_) => "MODIFY COLUMN",
+            }
+        }
+    }
+}
+
+/// Lightweight `DELETE FROM` statements
+pub mod delete {
+    use super::*;
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Query {
+        pub from: String,
+        #[serde(rename = "where")]
+        pub where_: Expr,
+    }
+}
+
+/// `EXPLAIN` statements, wrapping another query
+pub mod explain {
+    use super::*;
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields)]
+    pub struct Query {
+        #[serde(default)]
+        pub kind: Kind,
+        #[serde(default, skip_serializing_if = "settings::Settings::is_empty")]
+        pub settings: settings::Settings,
+        pub query: Box<super::Query>,
+    }
+
+    #[derive(Default, Deserialize, Serialize, PartialEq, Eq, Debug, Copy, Clone)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Kind {
+        #[default]
+        Plan,
+        Pipeline,
+        Syntax,
+        Estimate,
+        Ast,
+    }
+    impl Kind {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Self::Plan => "PLAN",
+                Self::Pipeline => "PIPELINE",
+                Self::Syntax => "SYNTAX",
+                Self::Estimate => "ESTIMATE",
+                Self::Ast => "AST",
+            }
+        }
+    }
+}
+
+/// Set operations (`UNION`, `INTERSECT`, `EXCEPT`) combining `SELECT` queries
+pub mod set_op {
+    use super::*;
+    #[derive(Deserialize, Serialize, Debug, PartialEq, Eq)]
+    #[serde(deny_unknown_fields, remote = "Self")]
+    pub struct Query {
+        #[serde(default)]
+        pub kind: Kind,
+        pub queries: Vec<select::Query>,
+    }
+    impl<'de> Deserialize<'de> for Query {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let query = Self::deserialize(deserializer)?;
+            if query.queries.len() < 2 {
+                return Err(D::Error::custom(
+                    "Set operations require at least two queries",
+                ));
+            }
+            Ok(query)
+        }
+    }
+    impl Serialize for Query {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            Self::serialize(self, serializer)
+        }
+    }
+
+    #[derive(Default, Deserialize, Serialize, PartialEq, Eq, Debug, Copy, Clone)]
+    #[serde(rename_all = "kebab-case")]
+    pub enum Kind {
+        #[default]
+        UnionAll,
+        UnionDistinct,
+        Intersect,
+        Except,
+    }
+    impl Kind {
+        pub fn name(&self) -> &'static str {
+            match self {
+                Kind::UnionAll => "UNION ALL",
+                Kind::UnionDistinct => "UNION DISTINCT",
+                Kind::Intersect => "INTERSECT",
+                Kind::Except => "EXCEPT",
+            }
+        }
     }
 }
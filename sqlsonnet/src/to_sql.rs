@@ -105,6 +105,22 @@ impl ToSql for Operator {
     }
 }
 
+// Single-quoted SQL string literal
+fn quote(s: &str) -> String {
+    let mut out = String::from("'");
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
 fn parenthesized_expr(f: &mut IndentedPrinter<'_>, expr: &Expr) -> fmt::Result {
     if expr.is_raw() {
         return expr.to_sql(f);
@@ -114,6 +130,23 @@ fn parenthesized_expr(f: &mut IndentedPrinter<'_>, expr: &Expr) -> fmt::Result {
     write!(f, ")")
 }
 
+// Subqueries need parentheses when nested in a literal or cast
+fn nested_expr(f: &mut IndentedPrinter<'_>, expr: &Expr) -> fmt::Result {
+    match expr {
+        Expr::Subquery(_) => parenthesized_expr(f, expr),
+        _ => expr.to_sql(f),
+    }
+}
+fn nested_exprs(f: &mut IndentedPrinter<'_>, exprs: &ExprList) -> fmt::Result {
+    for (i, expr) in exprs.0.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        nested_expr(f, expr)?;
+    }
+    Ok(())
+}
+
 impl ToSql for Expr {
     fn to_sql(&self, f: &mut IndentedPrinter<'_>) -> fmt::Result {
         match self {
@@ -121,6 +154,39 @@ impl ToSql for Expr {
             Expr::RawInteger(s) => write!(f, "{}", s),
             Expr::RawBool(s) => write!(f, "{:?}", s),
             Expr::RawFloat(s) => write!(f, "{}", s),
+            Expr::Null => write!(f, "NULL"),
+            Expr::String { string } => write!(f, "{}", quote(string)),
+            Expr::Array { array } => {
+                write!(f, "[")?;
+                nested_exprs(f, array)?;
+                write!(f, "]")
+            }
+            Expr::Tuple { tuple } => {
+                // (a) would just be a parenthesized expression
+                if tuple.0.len() < 2 {
+                    write!(f, "tuple")?;
+                }
+                write!(f, "(")?;
+                nested_exprs(f, tuple)?;
+                write!(f, ")")
+            }
+            Expr::Map { map } => {
+                write!(f, "map(")?;
+                for (i, (key, value)) in map.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}, ", quote(key))?;
+                    nested_expr(f, value)?;
+                }
+                write!(f, ")")
+            }
+            Expr::Cast { cast, r#type } => {
+                write!(f, "CAST(")?;
+                nested_expr(f, cast)?;
+                write!(f, " AS {})", r#type)
+            }
+            Expr::Param { name, r#type } => write!(f, "{{{}:{}}}", name, r#type),
             Expr::Aliased { expr, alias } => {
                 expr.to_sql(f)?;
                 write!(f, " AS {}", alias)
@@ -151,6 +217,22 @@ impl ToSql for Expr {
                 write!(f, " ")?;
                 parenthesized_expr(f, q2)
             }
+            Expr::IsNull { expr, not } => {
+                parenthesized_expr(f, expr)?;
+                write!(f, " IS {}NULL", if *not { "NOT " } else { "" })
+            }
+            Expr::Between {
+                expr,
+                low,
+                high,
+                not,
+            } => {
+                parenthesized_expr(f, expr)?;
+                write!(f, " {}BETWEEN ", if *not { "NOT " } else { "" })?;
+                parenthesized_expr(f, low)?;
+                write!(f, " AND ")?;
+                parenthesized_expr(f, high)
+            }
             Expr::Subquery(s) => {
                 writeln!(f)?;
                 ToSql::to_sql(s.as_ref(), &mut f.indented())
@@ -163,6 +245,51 @@ impl ToSql for Expr {
                 to_sql_list(&params.0, f, ", ")?;
                 write!(f, ")")
             }
+            Expr::Lambda { lambda, body } => {
+                if lambda.len() == 1 {
+                    write!(f, "{} -> ", lambda[0])?;
+                } else {
+                    write!(f, "({}) -> ", lambda.join(", "))?;
+                }
+                body.to_sql(f)
+            }
+            Expr::Case {
+                operand,
+                when,
+                else_,
+            } => {
+                write!(f, "CASE")?;
+                if let Some(operand) = operand {
+                    write!(f, " ")?;
+                    operand.to_sql(f)?;
+                }
+                writeln!(f)?;
+                let mut f2 = f.indented();
+                for (cond, result) in when {
+                    write!(f2, "WHEN ")?;
+                    cond.to_sql(&mut f2)?;
+                    write!(f2, " THEN ")?;
+                    result.to_sql(&mut f2)?;
+                    writeln!(f2)?;
+                }
+                if let Some(else_) = else_ {
+                    write!(f2, "ELSE ")?;
+                    else_.to_sql(&mut f2)?;
+                    writeln!(f2)?;
+                }
+                write!(f, "END")
+            }
+            Expr::Window {
+                r#fn: function,
+                params,
+                over,
+            } => {
+                write!(f, "{}(", function)?;
+                to_sql_list(&params.0, f, ", ")?;
+                write!(f, ") OVER (")?;
+                over.to_sql(f)?;
+                write!(f, ")")
+            }
         }
     }
 }
@@ -176,6 +303,22 @@ impl ToSql for Query {
     fn to_sql(&self, f: &mut IndentedPrinter<'_>) -> fmt::Result {
         match self {
             Query::Select(s) => s.to_sql(f),
+            Query::Insert(s) => s.to_sql(f),
+            Query::SetOp(s) => s.to_sql(f),
+            Query::CreateTable(s) => s.to_sql(f),
+            Query::CreateView(s) => s.to_sql(f),
+            Query::CreateMaterializedView(s) => s.to_sql(f),
+            Query::Alter(s) => s.to_sql(f),
+            Query::Delete(s) => s.to_sql(f),
+            Query::Explain(s) => s.to_sql(f),
+            Query::Raw(s) => {
+                // Statements are terminated by `Queries`
+                write!(f, "{}", s.trim_end().trim_end_matches(';').trim_end())?;
+                if !f.compact {
+                    writeln!(f)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -187,6 +330,28 @@ impl ToSql for from::From {
             Self::AliasedTable { table, alias } => {
                 write!(f, "{} AS {}", table, alias)
             }
+            Self::Function {
+                r#fn: function,
+                params,
+                alias,
+            } => {
+                write!(f, "{}(", function)?;
+                to_sql_list(&params.0, f, ", ")?;
+                write!(f, ")")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            Self::SetOp { query, alias } => {
+                writeln!(f, "(")?;
+                ToSql::to_sql(query.as_ref(), &mut f.indented())?;
+                write!(f, ")")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
             Self::Subquery { query, alias } => {
                 writeln!(f, "(")?;
                 ToSql::to_sql(query.as_ref(), &mut f.indented())?;
@@ -219,11 +384,10 @@ impl ToSql for join::On {
 }
 impl ToSql for join::Join {
     fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(f, "{} ", self.name())?;
         if self.on.is_empty() {
-            write!(f, "CROSS JOIN ")?;
             self.from.to_sql(f)
         } else {
-            write!(f, "{} ", self.kind.name())?;
             self.from.to_sql(f)?;
             writeln!(f)?;
             self.on.to_sql(&mut f.indented())
@@ -231,6 +395,45 @@ impl ToSql for join::Join {
     }
 }
 
+impl ToSql for group_by::GroupBy {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        match self {
+            Self::Exprs(exprs) => exprs.to_sql(&mut f.indented()),
+            Self::Modifiers {
+                exprs,
+                grouping_sets,
+                with,
+                totals,
+            } => {
+                exprs.to_sql(&mut f.indented())?;
+                if !grouping_sets.is_empty() {
+                    if !exprs.is_empty() {
+                        writeln!(f, ",")?;
+                    }
+                    let mut f2 = f.indented();
+                    write!(f2, "GROUPING SETS (")?;
+                    for (i, set) in grouping_sets.iter().enumerate() {
+                        if i > 0 {
+                            write!(f2, ", ")?;
+                        }
+                        write!(f2, "(")?;
+                        to_sql_list(&set.0, &mut f2, ", ")?;
+                        write!(f2, ")")?;
+                    }
+                    write!(f2, ")")?;
+                }
+                if let Some(with) = with {
+                    write!(f, "\n{}", with.name())?;
+                }
+                if *totals {
+                    write!(f, "\nWITH TOTALS")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 impl ToSql for order_by::Ordering {
     fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
         if *self != Self::Asc {
@@ -243,18 +446,170 @@ impl ToSql for order_by::Expr {
     fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
         match self {
             order_by::Expr::Asc(e) => e.to_sql(f),
-            order_by::Expr::Ordering { order, expr } => {
+            order_by::Expr::Ordering {
+                expr,
+                order,
+                nulls,
+                collate,
+                fill,
+            } => {
                 expr.to_sql(f)?;
                 order.to_sql(f)?;
+                if let Some(nulls) = nulls {
+                    write!(f, " {}", nulls.name())?;
+                }
+                if let Some(collate) = collate {
+                    write!(f, " COLLATE {}", quote(collate))?;
+                }
+                if let Some(fill) = fill {
+                    write!(f, " WITH FILL")?;
+                    for (name, expr) in
+                        [("FROM", &fill.from), ("TO", &fill.to), ("STEP", &fill.step)]
+                    {
+                        if let Some(expr) = expr {
+                            write!(f, " {} ", name)?;
+                            expr.to_sql(f)?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ToSql for window::Bound {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        match self {
+            Self::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            Self::Preceding(expr) => {
+                expr.to_sql(f)?;
+                write!(f, " PRECEDING")
+            }
+            Self::CurrentRow => write!(f, "CURRENT ROW"),
+            Self::Following(expr) => {
+                expr.to_sql(f)?;
+                write!(f, " FOLLOWING")
+            }
+            Self::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+impl ToSql for window::Frame {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(f, "{} ", self.units.name())?;
+        if let Some(end) = &self.end {
+            write!(f, "BETWEEN ")?;
+            self.start.to_sql(f)?;
+            write!(f, " AND ")?;
+            end.to_sql(f)
+        } else {
+            self.start.to_sql(f)
+        }
+    }
+}
+impl ToSql for window::Window {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        let mut separator = "";
+        if !self.partition_by.is_empty() {
+            write!(f, "PARTITION BY ")?;
+            to_sql_list(&self.partition_by.0, f, ", ")?;
+            separator = " ";
+        }
+        if !self.order_by.is_empty() {
+            write!(f, "{}ORDER BY ", separator)?;
+            to_sql_list(&self.order_by, f, ", ")?;
+            separator = " ";
+        }
+        if let Some(frame) = &self.frame {
+            write!(f, "{}", separator)?;
+            frame.to_sql(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToSql for sample::Ratio {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        match self {
+            Self::Decimal(ratio) => write!(f, "{}", ratio),
+            Self::Fraction(numerator, denominator) => write!(f, "{}/{}", numerator, denominator),
+        }
+    }
+}
+impl ToSql for sample::Sample {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        match self {
+            Self::Rows(rows) => write!(f, "{}", rows),
+            Self::Ratio(ratio) => ratio.to_sql(f),
+            Self::Offset { ratio, offset } => {
+                ratio.to_sql(f)?;
+                write!(f, " OFFSET ")?;
+                offset.to_sql(f)
+            }
+        }
+    }
+}
+
+impl ToSql for settings::Value {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        match self {
+            Self::Bool(b) => write!(f, "{}", b),
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::Float(x) => write!(f, "{}", x),
+            Self::String(s) => write!(f, "{}", quote(s)),
+        }
+    }
+}
+impl ToSql for settings::Settings {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        match self {
+            Self::Exprs(exprs) => exprs.to_sql(f),
+            Self::Map(map) => {
+                for (i, (name, value)) in map.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} = ", name)?;
+                    value.to_sql(f)?;
+                }
                 Ok(())
             }
         }
     }
 }
 
+impl ToSql for with::With {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        match self {
+            Self::Subquery { name, query } => {
+                writeln!(f, "{} AS (", name)?;
+                ToSql::to_sql(query.as_ref(), &mut f.indented())?;
+                write!(f, ")")
+            }
+            Self::Expr(expr) => expr.to_sql(f),
+        }
+    }
+}
+
 impl ToSql for select::Query {
     fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
-        writeln!(f, "SELECT")?;
+        if !self.with.is_empty() {
+            writeln!(f, "WITH")?;
+            self.with.to_sql(&mut f.indented())?;
+            writeln!(f)?;
+        }
+        write!(f, "SELECT")?;
+        match &self.distinct {
+            select::Distinct::Bool(false) => {}
+            select::Distinct::Bool(true) => write!(f, " DISTINCT")?,
+            select::Distinct::On(exprs) => {
+                write!(f, " DISTINCT ON (")?;
+                to_sql_list(&exprs.0, f, ", ")?;
+                write!(f, ")")?;
+            }
+        }
+        writeln!(f)?;
         if let Some(fields) = &self.fields {
             fields.to_sql(&mut f.indented())?;
         } else {
@@ -265,21 +620,33 @@ impl ToSql for select::Query {
             writeln!(f)?;
             write!(f, "FROM ")?;
             from.to_sql(f)?;
+            if self.final_ {
+                write!(f, " FINAL")?;
+            }
         }
         if let Some(sample) = &self.sample {
-            write!(f, "\nSAMPLE {}", sample)?;
+            write!(f, "\nSAMPLE ")?;
+            sample.to_sql(f)?;
+        }
+        for array_join in &self.array_join {
+            writeln!(f, "\n{}", array_join.name())?;
+            array_join.exprs.to_sql(&mut f.indented())?;
         }
         for join in &self.joins {
             writeln!(f)?;
             join.to_sql(f)?;
         }
+        if let Some(prewhere) = &self.prewhere {
+            writeln!(f, "\nPREWHERE")?;
+            prewhere.to_sql(&mut f.indented())?;
+        }
         if let Some(where_) = &self.where_ {
             writeln!(f, "\nWHERE")?;
             where_.to_sql(&mut f.indented())?;
         }
         if !self.group_by.is_empty() {
             writeln!(f, "\nGROUP BY")?;
-            self.group_by.to_sql(&mut f.indented())?;
+            self.group_by.to_sql(f)?;
         }
         if let Some(having) = &self.having {
             writeln!(f, "\nHAVING")?;
@@ -289,23 +656,258 @@ impl ToSql for select::Query {
             writeln!(f, "\nORDER BY")?;
             self.order_by.to_sql(&mut f.indented())?;
         }
-        if let Some(limit) = &self.limit {
-            write!(f, "\nLIMIT {}", limit)?;
-            if let Some(exprs) = &self.limit_by {
-                writeln!(f, " BY")?;
-                exprs.to_sql(&mut f.indented())?;
+        match &self.limit_by {
+            Some(limit::LimitBy::Full { limit, offset, by }) => {
+                write!(f, "\nLIMIT ")?;
+                if let Some(offset) = offset {
+                    write!(f, "{}, ", offset)?;
+                }
+                writeln!(f, "{} BY", limit)?;
+                by.to_sql(&mut f.indented())?;
+            }
+            Some(limit::LimitBy::Exprs(by)) => {
+                // Legacy form, where `limit` applies to the `BY` clause.
+                if let Some(limit) = &self.limit {
+                    writeln!(f, "\nLIMIT {} BY", limit.rows())?;
+                    by.to_sql(&mut f.indented())?;
+                }
+            }
+            None => {}
+        }
+        let limit = match &self.limit_by {
+            Some(limit::LimitBy::Exprs(_)) => None,
+            _ => self.limit.as_ref(),
+        };
+        let mut offset = self.offset;
+        if let Some(limit) = limit {
+            write!(f, "\nLIMIT ")?;
+            // WITH TIES directly follows the LIMIT clause, which then carries the offset.
+            let limit_offset = match limit.offset() {
+                None if limit.with_ties() => offset.take(),
+                limit_offset => limit_offset,
+            };
+            if let Some(offset) = limit_offset {
+                write!(f, "{}, ", offset)?;
+            }
+            write!(f, "{}", limit.rows())?;
+            if limit.with_ties() {
+                write!(f, " WITH TIES")?;
             }
         }
-        if let Some(offset) = &self.offset {
+        if let Some(offset) = offset {
             write!(f, "\nOFFSET {}", offset)?;
         }
         if !self.settings.is_empty() {
             write!(f, "\nSETTINGS ")?;
             self.settings.to_sql(f)?;
         }
+        if let Some(into_outfile) = &self.into_outfile {
+            write!(f, "\nINTO OUTFILE {}", quote(into_outfile))?;
+        }
+        if let Some(format) = &self.format {
+            write!(f, "\nFORMAT {}", format)?;
+        }
+        if !f.compact {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToSql for insert::Query {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(f, "INSERT INTO {}", self.into)?;
+        if !self.columns.is_empty() {
+            write!(f, " ({})", self.columns.join(", "))?;
+        }
+        writeln!(f)?;
+        self.select.to_sql(f)
+    }
+}
+
+fn if_not_exists(if_not_exists: bool) -> &'static str {
+    if if_not_exists {
+        "IF NOT EXISTS "
+    } else {
+        ""
+    }
+}
+
+impl ToSql for create::Column {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.r#type)?;
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT ")?;
+            default.to_sql(f)?;
+        }
+        if !self.codec.is_empty() {
+            write!(f, " CODEC(")?;
+            to_sql_list(&self.codec.0, f, ", ")?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl ToSql for create::Storage {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        if let Some(engine) = &self.engine {
+            write!(f, "\nENGINE = ")?;
+            engine.to_sql(f)?;
+        }
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, "\nPARTITION BY ")?;
+            partition_by.to_sql(f)?;
+        }
+        if !self.order_by.is_empty() {
+            write!(f, "\nORDER BY ")?;
+            if self.order_by.0.len() == 1 {
+                self.order_by.0[0].to_sql(f)?;
+            } else {
+                write!(f, "(")?;
+                to_sql_list(&self.order_by.0, f, ", ")?;
+                write!(f, ")")?;
+            }
+        }
+        if !self.ttl.is_empty() {
+            write!(f, "\nTTL ")?;
+            to_sql_list(&self.ttl.0, f, ", ")?;
+        }
+        if !self.settings.is_empty() {
+            write!(f, "\nSETTINGS ")?;
+            self.settings.to_sql(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToSql for create::Table {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        writeln!(
+            f,
+            "CREATE TABLE {}{} (",
+            if_not_exists(self.if_not_exists),
+            self.name
+        )?;
+        self.columns.to_sql(&mut f.indented())?;
+        if !f.compact {
+            writeln!(f)?;
+        }
+        write!(f, ")")?;
+        self.storage.to_sql(f)?;
+        if !f.compact {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToSql for create::View {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(f, "CREATE ")?;
+        if self.or_replace {
+            write!(f, "OR REPLACE ")?;
+        }
+        writeln!(
+            f,
+            "VIEW {}{} AS",
+            if_not_exists(self.if_not_exists),
+            self.name
+        )?;
+        self.select.to_sql(f)
+    }
+}
+
+impl ToSql for create::MaterializedView {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE MATERIALIZED VIEW {}{}",
+            if_not_exists(self.if_not_exists),
+            self.name
+        )?;
+        if let Some(to) = &self.to {
+            write!(f, " TO {}", to)?;
+        }
+        self.storage.to_sql(f)?;
+        if self.populate {
+            write!(f, "\nPOPULATE")?;
+        }
+        writeln!(f, "\nAS")?;
+        self.select.to_sql(f)
+    }
+}
+
+impl ToSql for alter::Command {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(f, "{} ", self.name())?;
+        match self {
+            Self::Update { set, where_ } => {
+                for (i, (column, expr)) in set.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} = ", column)?;
+                    expr.to_sql(f)?;
+                }
+                write!(f, "\nWHERE ")?;
+                where_.to_sql(f)
+            }
+            Self::Delete { where_ } => {
+                write!(f, "WHERE ")?;
+                where_.to_sql(f)
+            }
+            Self::AddColumn(column) | Self::ModifyColumn(column) => column.to_sql(f),
+            Self::DropColumn(column) => write!(f, "{}", column),
+        }
+    }
+}
+
+impl ToSql for alter::Query {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        writeln!(f, "ALTER TABLE {}", self.table)?;
+        self.commands.to_sql(&mut f.indented())?;
+        if !f.compact {
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToSql for delete::Query {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        writeln!(f, "DELETE FROM {}\nWHERE", self.from)?;
+        self.where_.to_sql(&mut f.indented())?;
         if !f.compact {
             writeln!(f)?;
         }
         Ok(())
     }
 }
+
+impl ToSql for explain::Query {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        write!(f, "EXPLAIN {}", self.kind.name())?;
+        if !self.settings.is_empty() {
+            write!(f, " ")?;
+            self.settings.to_sql(f)?;
+        }
+        writeln!(f)?;
+        ToSql::to_sql(self.query.as_ref(), f)
+    }
+}
+
+impl ToSql for set_op::Query {
+    fn to_sql(&self, f: &mut IndentedPrinter) -> fmt::Result {
+        for (i, query) in self.queries.iter().enumerate() {
+            if i > 0 {
+                if f.compact {
+                    write!(f, " ")?;
+                }
+                writeln!(f, "{}", self.kind.name())?;
+            }
+            query.to_sql(f)?;
+        }
+        Ok(())
+    }
+}
@@ -48,3 +48,325 @@ SETTINGS setting1 = 0
 SELECT
   a
 ;
+INSERT INTO db.table9 (a, b)
+SELECT
+  a,
+  b
+FROM table10
+;
+WITH
+  1 AS a,
+  t AS (
+    SELECT
+      x
+    FROM table11
+  )
+SELECT
+  a,
+  x
+FROM t
+;
+SELECT
+  a
+FROM table12
+UNION ALL
+SELECT
+  a
+FROM table13
+;
+SELECT
+  *
+FROM (
+  SELECT
+    a
+  FROM table14
+  INTERSECT
+  SELECT
+    a
+  FROM table15
+) AS s
+WHERE
+  a IN (
+    SELECT
+      a
+    FROM table16
+    UNION DISTINCT
+    SELECT
+      a
+    FROM table17
+  )
+;
+SELECT
+  a,
+  row_number() OVER (PARTITION BY a, b ORDER BY c DESC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS r,
+  sum(c) OVER (ORDER BY d RANGE 1 PRECEDING),
+  count() OVER ()
+FROM table18
+;
+SELECT
+  a,
+  b
+FROM table19
+ARRAY JOIN
+  arr AS a
+LEFT ARRAY JOIN
+  arr2 AS b,
+  arr3
+WHERE
+  a > 1
+;
+SELECT
+  a
+FROM table20 FINAL
+PREWHERE
+  b = 1
+WHERE
+  a > 1
+;
+SELECT
+  a,
+  b,
+  sum(c)
+FROM table21
+GROUP BY
+  a,
+  b
+WITH ROLLUP
+WITH TOTALS
+;
+SELECT
+  a,
+  b,
+  sum(c)
+FROM table22
+GROUP BY
+  GROUPING SETS ((a, b), (a), ())
+;
+SELECT DISTINCT
+  a,
+  distinct_count
+FROM table23
+;
+SELECT DISTINCT ON (a, b)
+  a,
+  b,
+  c
+FROM table24
+;
+SELECT
+  d,
+  count()
+FROM table25
+GROUP BY
+  d
+ORDER BY
+  d DESC NULLS LAST WITH FILL FROM toDate('2024-01-01') TO today() STEP 1,
+  s NULLS FIRST COLLATE 'en',
+  e WITH FILL
+;
+SELECT
+  a,
+  b
+FROM table26
+ORDER BY
+  b
+LIMIT 1, 2 BY
+  a
+LIMIT 5, 10
+;
+SELECT
+  a
+FROM table27
+ORDER BY
+  a
+LIMIT 2 BY
+  b
+LIMIT 5, 10 WITH TIES
+;
+SELECT
+  a
+FROM table28
+SAMPLE 0.1
+;
+SELECT
+  a
+FROM table29
+SAMPLE 1/10 OFFSET 1/2
+;
+SELECT
+  a
+FROM table30
+SAMPLE 1000
+OFFSET 10
+;
+SELECT
+  a
+FROM table31
+WHERE
+  b = 'it\'s'
+SETTINGS join_algorithm = 'parallel_hash', max_threads = 8, optimize_read_in_order = false, x = -0.5
+;
+SELECT
+  a
+FROM table32
+SETTINGS max_threads = 1
+INTO OUTFILE 'out.json'
+FORMAT JSONEachRow
+;
+SELECT
+  number
+FROM numbers(10) AS n
+;
+SELECT
+  *
+FROM my_view(a = 1, b = 'x')
+JOIN remote('host:9000', db.table33) AS r
+  USING
+    a
+;
+SELECT
+  *
+FROM table34
+LEFT OUTER JOIN table35
+  USING
+    a
+GLOBAL ANY JOIN table36
+  USING
+    b
+RIGHT SEMI JOIN table37
+  USING
+    c
+FULL ALL JOIN table38
+  USING
+    d
+LEFT ASOF JOIN table39
+  ON
+    e = e2 AND t >= t2
+GLOBAL LEFT ANTI JOIN (
+  SELECT
+    f
+  FROM table40
+  JOIN table41
+    USING
+      g
+) AS s
+  USING
+    f
+CROSS JOIN table42
+;
+SELECT
+  arrayMap(x -> x * 2, a),
+  arraySort((x, y) -> y, a, b) AS s
+FROM table43
+WHERE
+  arrayExists(x -> x > 1, a)
+;
+SELECT
+  CAST(a AS Nullable(String)),
+  b::UInt8,
+  [1, 2],
+  NULL,
+  'it\'s'
+FROM table44
+WHERE
+  (a, b) IN (
+    SELECT
+      a,
+      b
+    FROM table45
+  )
+;
+SELECT
+  CAST((
+    SELECT
+      max(a)
+    FROM table46
+  ) AS UInt64),
+  (1, (
+    SELECT
+      1
+  )),
+  [(
+    SELECT
+      2
+  )]
+;
+SELECT
+  a
+FROM table47
+WHERE
+  b = {b:UInt64} AND c IN {c:Array(String)}
+;
+SELECT
+  a
+FROM table48
+WHERE
+  a != 1 AND b <> 2 AND c % 2 = 0 AND d || 'x' ILIKE '%x' AND e NOT IN (1, 2) AND f IS NULL AND g IS NOT NULL AND h NOT BETWEEN 1 AND 10 AND i GLOBAL NOT IN t AND j NOT LIKE 'a%' OR k == 1
+;
+SELECT
+  a
+FROM table49
+WHERE
+  (((a IS NOT NULL)
+  AND ((b + (1 * 2)) BETWEEN 1 AND (
+    SELECT
+      max(b)
+    FROM table50
+  )))
+  AND (c GLOBAL IN (
+    SELECT
+      c
+    FROM table51
+  )))
+  OR (d = (1 + (2 * 3)))
+;
+EXPLAIN PIPELINE max_threads = 2
+SELECT
+  a
+FROM table52
+;
+EXPLAIN AST
+INSERT INTO table53
+SELECT
+  a
+FROM table54
+;
+SELECT
+  *
+FROM table55
+JOIN table56
+  USING
+    a,
+    b AS c
+JOIN table57 AS t
+  ON
+    (t.x = table55.x)
+    AND (t.y IN (
+      SELECT
+        y
+      FROM table58
+    ))
+CROSS JOIN table59
+;
+SELECT
+  a
+FROM table60
+WHERE
+  x IN (1, 2)
+INTERSECT
+SELECT
+  a
+FROM table61
+;
+SELECT
+  a
+FROM table62
+WHERE
+  x > 1
+EXCEPT
+SELECT
+  a
+FROM table63
+WHERE
+  interval_id = 1
+;
@@ -27,6 +27,85 @@ fn sql_roundtrip() -> anyhow::Result<()> {
     Ok(())
 }
 
+// Set operators must not be read as infix operators of a preceding WHERE clause.
+#[test]
+#[cfg(feature = "from-sql")]
+fn set_op_after_where() -> anyhow::Result<()> {
+    for (sql, kind) in [
+        (
+            "SELECT a FROM t1 WHERE x IN (1, 2) INTERSECT SELECT a FROM t2",
+            sqlsonnet::queries::set_op::Kind::Intersect,
+        ),
+        (
+            "SELECT a FROM t1 WHERE x > 1 EXCEPT SELECT a FROM t2",
+            sqlsonnet::queries::set_op::Kind::Except,
+        ),
+    ] {
+        let Query::SetOp(query) = Query::from_sql(sql)? else {
+            panic!("Expected a set operation: {}", sql);
+        };
+        assert_eq!(query.kind, kind);
+        assert_eq!(query.queries.len(), 2);
+    }
+    Ok(())
+}
+
+// Clickhouse accepts the strictness on either side of the join kind.
+#[test]
+#[cfg(feature = "from-sql")]
+fn join_strictness_order() -> anyhow::Result<()> {
+    for (sql, expected) in [
+        (
+            "SELECT * FROM a ANY LEFT JOIN b USING id",
+            "SELECT * FROM a LEFT ANY JOIN b USING id",
+        ),
+        (
+            "SELECT * FROM a ASOF LEFT JOIN b ON a.t >= b.t",
+            "SELECT * FROM a LEFT ASOF JOIN b ON a.t >= b.t",
+        ),
+    ] {
+        let query = Query::from_sql(sql)?;
+        assert_eq!(query, Query::from_sql(expected)?);
+        assert_eq!(query.to_sql(true), expected);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "from-sql")]
+fn limit_with_ties() -> anyhow::Result<()> {
+    assert_eq!(
+        Query::from_sql("SELECT a FROM t ORDER BY a LIMIT 10 OFFSET 5 WITH TIES")?,
+        Query::from_sql("SELECT a FROM t ORDER BY a LIMIT 5, 10 WITH TIES")?
+    );
+    assert!(Query::from_sql("SELECT a FROM t ORDER BY a WITH TIES").is_err());
+    assert!(Query::from_sql("SELECT a FROM t LIMIT 5, 10 OFFSET 5").is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "from-sql")]
+fn settings_order() -> anyhow::Result<()> {
+    let query =
+        Query::from_sql("SELECT a FROM t SETTINGS max_threads=8, join_algorithm=parallel_hash")?;
+    assert_eq!(
+        query.to_sql(true),
+        "SELECT a FROM t SETTINGS max_threads = 8, join_algorithm = 'parallel_hash'"
+    );
+    Ok(())
+}
+
+// Multi-word operators may be separated by any whitespace.
+#[test]
+#[cfg(feature = "from-sql")]
+fn operator_whitespace() -> anyhow::Result<()> {
+    assert_eq!(
+        Query::from_sql("SELECT a FROM t WHERE x NOT\n  IN (SELECT 1) AND y IS  NOT\tNULL")?,
+        Query::from_sql("SELECT a FROM t WHERE x NOT IN (SELECT 1) AND y IS NOT NULL")?
+    );
+    Ok(())
+}
+
 // TODO: This would be simpler with a trait on Query/Queries.
 macro_rules! run_impl {
     ($i: ident, $t:ty) => {
@@ -61,7 +140,37 @@ mod jsonnet_to_sql {
             $( jsonnet_to_sql!($filename); )+
         };
     }
-    jsonnet_to_sql!(function_call, parenthesization, as_override, empty_fields);
+    jsonnet_to_sql!(
+        function_call,
+        parenthesization,
+        as_override,
+        empty_fields,
+        insert,
+        with,
+        set_op,
+        window,
+        case,
+        array_join,
+        final_prewhere,
+        group_by,
+        distinct,
+        order_by,
+        limit,
+        sample,
+        settings,
+        format,
+        table_function,
+        join,
+        lambda,
+        literals,
+        param,
+        operators,
+        create_table,
+        create_view,
+        alter,
+        delete,
+        explain
+    );
 }
 
 #[test]
@@ -82,6 +191,103 @@ fn queries_single() -> anyhow::Result<()> {
     Ok(())
 }
 
+// Unsupported statements are passed through alongside other queries.
+#[test]
+fn queries_raw() -> anyhow::Result<()> {
+    let queries = run_queries("[u.raw('SYSTEM FLUSH LOGS'), u.select({ fields: [1] })]")?;
+    assert_eq!(queries.to_sql(true), "SYSTEM FLUSH LOGS; SELECT 1; ");
+    let queries = run_queries("[u.raw('SYSTEM FLUSH LOGS;\\n')]")?;
+    assert_eq!(queries.to_sql(true), "SYSTEM FLUSH LOGS; ");
+    Ok(())
+}
+
+#[test]
+fn join_on() -> anyhow::Result<()> {
+    let query =
+        run_query("u.select({ from: 'a', joins: [{ from: 'b', on: [u.eq('a.x', 'b.x')] }] })")?;
+    assert_eq!(query.to_sql(true), "SELECT * FROM a JOIN b ON a.x = b.x");
+    let query = run_query(
+        "u.select({ from: 'a', joins: [{ from: 'b', on: ['a.x = b.x', 'a.y = b.y'] }] })",
+    )?;
+    assert_eq!(
+        query.to_sql(true),
+        "SELECT * FROM a JOIN b ON a.x = b.x AND a.y = b.y"
+    );
+    assert!(run_query("u.select({ from: 'a', joins: [{ from: 'b', on: '' }] })").is_err());
+    Ok(())
+}
+
+#[test]
+fn limit_offset() -> anyhow::Result<()> {
+    let query =
+        run_query("{ select: { from: 't', limit: { limit: 10, withTies: true }, offset: 5 } }")?;
+    assert_eq!(query.to_sql(true), "SELECT * FROM t LIMIT 5, 10 WITH TIES");
+    assert!(
+        run_query("{ select: { from: 't', limit: { limit: 10, offset: 5 }, offset: 5 } }").is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn materialized_view_to() -> anyhow::Result<()> {
+    let view = |extra: &str| {
+        run_query(&format!(
+            "u.create_materialized_view({{ name: 'v', to: 't', select: {{ from: 's' }} {} }})",
+            extra
+        ))
+    };
+    assert_eq!(
+        view("")?.to_sql(true),
+        "CREATE MATERIALIZED VIEW v TO t AS SELECT * FROM s"
+    );
+    assert!(view(", engine: u.fn('MergeTree', [])").is_err());
+    assert!(view(", populate: true").is_err());
+    Ok(())
+}
+
+#[test]
+fn alter_pretty() -> anyhow::Result<()> {
+    let query = run_query(
+        "u.alter('t', [{ update: { set: { a: 1 }, where: u.and([u.eq('id', 1), u.eq('b', 2)]) } }, { delete: { where: u.eq('id', 1) } }])",
+    )?;
+    assert_eq!(
+        query.to_sql(false),
+        "ALTER TABLE t\n  UPDATE a = 1\n  WHERE (id = 1)\n  AND (b = 2),\n  DELETE WHERE id = 1\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn join_strictness_kind() {
+    let join = |kind: &str, strictness: &str| {
+        run_query(&format!(
+            "u.select({{ from: 'a', joins: [{{ from: 'b', kind: '{}', strictness: '{}', using: ['id'] }}] }})",
+            kind, strictness
+        ))
+    };
+    assert!(join("inner", "semi").is_err());
+    assert!(join("full-outer", "anti").is_err());
+    assert!(join("right-outer", "asof").is_err());
+    assert!(join("right-outer", "semi").is_ok());
+    assert!(join("inner", "asof").is_ok());
+}
+
+#[test]
+fn set_op_queries() -> anyhow::Result<()> {
+    assert!(run_query("u.union_all([])").is_err());
+    assert!(run_query("u.union_all([{ fields: [1] }])").is_err());
+    assert_eq!(
+        run_query("u.union_all([{ fields: [1] }, { fields: [2] }])")?.to_sql(true),
+        "SELECT 1 UNION ALL SELECT 2"
+    );
+    Ok(())
+}
+
+#[test]
+fn distinct_empty() {
+    assert!(run_query("u.select({ distinct: [], fields: ['a'], from: 't' })").is_err());
+}
+
 #[test]
 fn query_macro() -> anyhow::Result<()> {
     let sql = sqlsonnet::sqlsonnet_query!(